@@ -1,5 +1,5 @@
 use one_inch::{
-    client::{self, SupportedNetworks},
+    client::{OneInchClient, SupportedNetworks},
     swap::approve::*,
 };
 
@@ -12,12 +12,12 @@ async fn main() {
     let token = env!("ONE_INCH_API_TOKEN");
 
     // Creating a new One Inch client with the provided API token and network ID
-    let client = client::new_with_default_http(token.into(), network_id);
+    let client = OneInchClient::builder().token(token.into()).network_id(network_id).build().unwrap();
 
     // Creating allowance details using the AllowanceDetailsBuilder
     let allowance_details = AllowanceDetailsBuilder::new()
-        .wallet_address("0x30A557351eab496FD69F537BE1F8c744A18F94Fd".into())
-        .token_address("0x55d398326f99059ff775485246999027b3197955".into())
+        .wallet_address("0x30A557351eab496FD69F537BE1F8c744A18F94Fd".parse().unwrap())
+        .token_address("0x55d398326f99059ff775485246999027b3197955".parse().unwrap())
         .build()
         .unwrap();
 
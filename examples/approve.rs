@@ -1,5 +1,6 @@
 use one_inch::{
-    client::{self, SupportedNetworks},
+    client::{OneInchClient, SupportedNetworks},
+    common::address::Address,
     swap::approve::*,
 };
 
@@ -12,13 +13,13 @@ async fn main() {
     let token = env!("ONE_INCH_API_TOKEN");
 
     // Creating a new One Inch client with the provided API token and network ID
-    let client = client::new_with_default_http(token.into(), network_id);
+    let client = OneInchClient::builder().token(token.into()).network_id(network_id).build().unwrap();
 
-    let usdt_address_bsc = "0x55d398326f99059ff775485246999027b3197955".to_string();
+    let usdt_address_bsc: Address = "0x55d398326f99059ff775485246999027b3197955".parse().unwrap();
 
     // Getting raw tx that can be used to create transaction in blockchain
     let approve_details = ApproveTranactionDetailsBuilder::new()
-        .amount(Some("5000000000000000".to_string()))
+        .amount(Some("5000000000000000".parse().unwrap()))
         .token_address(usdt_address_bsc)
         .build()
         .unwrap();
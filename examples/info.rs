@@ -1,5 +1,7 @@
-use one_inch::client::{self, SupportedNetworks};
-use std::time::Duration;
+use one_inch::{
+    client::{OneInchClient, SupportedNetworks},
+    retry::RetryPolicy,
+};
 
 /*
  In this example we will get list of tokens that are available for swap in the 1inch
@@ -14,16 +16,15 @@ async fn main() {
     // Retrieving the API token from the environment variables
     let token = env!("ONE_INCH_API_TOKEN");
 
-    // Creating a new One Inch client with the provided API token and network ID
-    let client = client::new_with_default_http(token.into(), network_id);
+    // Creating a new One Inch client with the provided API token and network ID.
+    // Requests rejected because of server restrictions will be retried automatically.
+    let client =
+        OneInchClient::builder().token(token.into()).network_id(network_id).retry_policy(RetryPolicy::default()).build().unwrap();
 
     let tokens_list_response =
         client.get_tokens_list().await.map_err(|e| eprintln!("Got error while getting tokens list on 1inch : {e:?}")).unwrap();
     println!("Amount on tokens in 1inch in BSC is : {}", tokens_list_response.tokens.len());
 
-    println!("Sleeping 5 seconds before making a second request..");
-    tokio::time::sleep(Duration::from_secs(5)).await;
-
     let lp_pools_response = client
         .get_liquidity_sources()
         .await
@@ -0,0 +1,40 @@
+use one_inch::{
+    client::{OneInchClient, SupportedNetworks},
+    rate_limit::RateLimiter,
+};
+
+/*
+ In this example we will use a single client to get the amount of tokens available for swap
+ on several networks at once. All the requests share the same connection pool and rate limiter.
+*/
+
+#[tokio::main]
+async fn main() {
+    // Retrieving the API token from the environment variables
+    let token = std::env::var("ONE_INCH_API_TOKEN").expect("ONE_INCH_API_TOKEN must be set");
+
+    // Creating a new One Inch client. Requests are limited to 1 per second for all the networks together.
+    let client = OneInchClient::builder().token(token).rate_limiter(RateLimiter::new(1.0, 1)).build().unwrap();
+
+    let networks = [SupportedNetworks::Ethereum, SupportedNetworks::BSC, SupportedNetworks::Polygon, SupportedNetworks::Arbitrum];
+
+    // Sending the same request to every network concurrently
+    let results = client.fan_out(networks, |client| async move { client.get_tokens_list().await }).await;
+
+    for (network, result) in results {
+        match result {
+            Ok(tokens_list) => println!("Amount of tokens in 1inch in {:?} is : {}", network, tokens_list.tokens.len()),
+            Err(e) => eprintln!("Got error while getting tokens list in {:?} : {}", network, e),
+        }
+    }
+
+    // Or sending a single request to another network
+    let lp_pools_response = client
+        .on_chain(SupportedNetworks::Optimism)
+        .get_liquidity_sources()
+        .await
+        .map_err(|e| eprintln!("Got error while getting liquidity pools on 1inch : {e:?}"))
+        .unwrap();
+
+    println!("Amount of liquidity sources in Optimism is : {}", lp_pools_response.protocols.len())
+}
@@ -1,8 +1,9 @@
 use one_inch::{
-    client::{self, SupportedCurrencies, SupportedNetworks},
+    client::{OneInchClient, SupportedCurrencies, SupportedNetworks},
+    common::address::Address,
+    retry::RetryPolicy,
     tokens::tokens_price::TokensPricesRequestBuilder,
 };
-use std::time::Duration;
 
 #[tokio::main]
 async fn main() {
@@ -12,8 +13,10 @@ async fn main() {
     // Retrieving the API token from the environment variables
     let token = env!("ONE_INCH_API_TOKEN");
 
-    // Creating a new One Inch client with the provided API token and network ID
-    let client = client::new_with_default_http(token.into(), network_id);
+    // Creating a new One Inch client with the provided API token and network ID.
+    // Requests rejected because of server restrictions will be retried automatically.
+    let client =
+        OneInchClient::builder().token(token.into()).network_id(network_id).retry_policy(RetryPolicy::default()).build().unwrap();
 
     // Getting all the custom currencies
     let currencies =
@@ -29,15 +32,12 @@ async fn main() {
 
     println!();
 
-    // timeout of 5 seconds to avoid server restrictions
-    std::thread::sleep(Duration::from_secs(5));
-
     // Getting prices for couple of specified tokens
-    let my_tokens_list: Vec<String> = vec![
-        "0xce7de646e7208a4ef112cb6ed5038fa6cc6b12e3".into(), // TRX
-        "0x1d2f0da169ceb9fc7b3144628db156f3f6c60dbe".into(), // XRP
-        "0xba2ae424d960c26247dd6c32edc70b295c744c43".into(), // DOGE
-        "0x7083609fce4d1d8dc0c979aab8c869ea2c873402".into(), // DOT
+    let my_tokens_list: Vec<Address> = vec![
+        "0xce7de646e7208a4ef112cb6ed5038fa6cc6b12e3".parse().unwrap(), // TRX
+        "0x1d2f0da169ceb9fc7b3144628db156f3f6c60dbe".parse().unwrap(), // XRP
+        "0xba2ae424d960c26247dd6c32edc70b295c744c43".parse().unwrap(), // DOGE
+        "0x7083609fce4d1d8dc0c979aab8c869ea2c873402".parse().unwrap(), // DOT
     ];
 
     let get_prices_details =
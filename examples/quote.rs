@@ -1,5 +1,7 @@
 use one_inch::{
-    client::{self, SupportedNetworks},
+    client::{OneInchClient, SupportedNetworks},
+    common::{address::Address, amount::TokenAmount},
+    error::OneInchError,
     swap::QuoteDetailsBuilder,
 };
 use std::time::Duration;
@@ -10,19 +12,19 @@ async fn main() {
     let network_id = SupportedNetworks::BSC;
 
     // Contract addresses of tokens we want to swap
-    let src = "0x55d398326f99059ff775485246999027b3197955".to_string(); // USDT address in bsc
-    let dst = "0x1D2F0da169ceB9fC7B3144628dB156f3F6c60dBE".to_string(); // XRP address in bsc
-    let bnb_in_wei = "1000000000000000000".to_string(); //(10 ^ -18)
+    let src: Address = "0x55d398326f99059ff775485246999027b3197955".parse().unwrap(); // USDT address in bsc
+    let dst: Address = "0x1D2F0da169ceB9fC7B3144628dB156f3F6c60dBE".parse().unwrap(); // XRP address in bsc
+    let bnb_in_wei = TokenAmount::from_units("1", 18).unwrap(); // 1 token with 18 decimals in its smallest units
 
     // Retrieving the API token from the environment variables
     let token = env!("ONE_INCH_API_TOKEN");
 
     // Creating a new One Inch client with the provided API token and network ID
-    let client = client::new_with_default_http(token.into(), network_id);
+    let client = OneInchClient::builder().token(token.into()).network_id(network_id).build().unwrap();
 
     // Making basic swap request
     let simple_quote_details =
-        QuoteDetailsBuilder::new().amount(bnb_in_wei.clone()).src(src.clone()).dst(dst.clone()).fee(2).unwrap().build().unwrap();
+        QuoteDetailsBuilder::new().amount(bnb_in_wei.clone()).src(src).dst(dst).fee(2).unwrap().build().unwrap();
 
     let basic_quote = client
         .quote(simple_quote_details)
@@ -42,8 +44,8 @@ async fn main() {
 
     let extended_quote_details = QuoteDetailsBuilder::new()
         .amount(bnb_in_wei.clone())
-        .src(src.clone())
-        .dst(dst.clone())
+        .src(src)
+        .dst(dst)
         .fee(2)
         .unwrap()
         .include_gas(true)
@@ -70,5 +72,14 @@ async fn main() {
 
     let error_quote = client.quote(error_swap_details).await;
 
-    println!("Got error(which is good!) for third quote request : {:#?}", error_quote.unwrap_err());
+    // Every endpoint returns `OneInchError`, so we can simply match on the kind of failure
+    match error_quote.unwrap_err() {
+        OneInchError::RateLimited { retry_after } => {
+            println!(
+                "Got rate limited(which is good!) for third quote request, retry after : {:?}",
+                retry_after
+            )
+        }
+        e => println!("Got error for third quote request : {:#?}", e),
+    }
 }
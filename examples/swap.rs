@@ -1,8 +1,9 @@
 use one_inch::{
-    client::{self, SupportedNetworks},
+    client::{OneInchClient, SupportedNetworks},
+    common::{address::Address, amount::TokenAmount},
+    retry::RetryPolicy,
     swap::SwapDetailsBuilder,
 };
-use std::time::Duration;
 
 #[tokio::main]
 async fn main() {
@@ -10,23 +11,25 @@ async fn main() {
     let network_id = SupportedNetworks::BSC;
 
     // Contract addresses of tokens we want to swap
-    let src = "0x55d398326f99059ff775485246999027b3197955".to_string(); // USDT address in bsc
-    let dst = "0x1D2F0da169ceB9fC7B3144628dB156f3F6c60dBE".to_string(); // XRP address in bsc
-    let my_address = "0x13961a09bCD42DCC078765286Be746d87f20E82e".to_string();
-    let bnb_in_wei = "1000000000000000000".to_string(); //(10 ^ -18)
+    let src: Address = "0x55d398326f99059ff775485246999027b3197955".parse().unwrap(); // USDT address in bsc
+    let dst: Address = "0x1D2F0da169ceB9fC7B3144628dB156f3F6c60dBE".parse().unwrap(); // XRP address in bsc
+    let my_address: Address = "0x13961a09bCD42DCC078765286Be746d87f20E82e".parse().unwrap();
+    let bnb_in_wei = TokenAmount::from_units("1", 18).unwrap(); // 1 token with 18 decimals in its smallest units
 
     // Retrieving the API token from the environment variables
     let token = env!("ONE_INCH_API_TOKEN");
 
-    // Creating a new One Inch client with the provided API token and network ID
-    let client = client::new_with_default_http(token.into(), network_id);
+    // Creating a new One Inch client with the provided API token and network ID.
+    // Requests rejected because of server restrictions will be retried automatically.
+    let client =
+        OneInchClient::builder().token(token.into()).network_id(network_id).retry_policy(RetryPolicy::default()).build().unwrap();
 
     // Making basic swap request
     let swap_details = SwapDetailsBuilder::new()
         .amount(bnb_in_wei.clone())
-        .from_addr(my_address.clone())
-        .src(src.clone())
-        .dst(dst.clone())
+        .from_addr(my_address)
+        .src(src)
+        .dst(dst)
         .slippage(2)
         .unwrap()
         .build()
@@ -43,15 +46,12 @@ async fn main() {
 
     println!("Response for perfoming basic swap : {:#?}", basic_swap);
 
-    // timeout of 5 seconds to avoid server restrictions
-    tokio::time::sleep(Duration::from_secs(5)).await;
-
     // Making swap request with some additional parameters
     let extended_swap_details = SwapDetailsBuilder::new()
         .amount(bnb_in_wei.clone())
-        .from_addr(my_address.clone())
-        .src(src.clone())
-        .dst(dst.clone())
+        .from_addr(my_address)
+        .src(src)
+        .dst(dst)
         .slippage(2)
         .unwrap()
         .include_tokens_info(true)
@@ -71,9 +71,6 @@ async fn main() {
 
     println!("Response for perfoming another swap : {:#?}", extended_swap);
 
-    // timeout of 5 seconds to avoid server restrictions
-    tokio::time::sleep(Duration::from_secs(5)).await;
-
     // Making swap request where server should return error.
     let error_swap_details = SwapDetailsBuilder::new()
         .amount(bnb_in_wei)
@@ -0,0 +1,148 @@
+use crate::{
+    balance::{AggregatedBalance, BalanceAndAllowance, TokenBalances, TokensFetchType, TokensRequest, WalletsAndTokensRequest},
+    client::OneInchClient,
+    common::address::Address,
+    consts::BALANCE_API_VERSION,
+    error::OneInchError,
+    utils::{
+        params::insert_optional_param,
+        request::{parse_url, parse_url_with_params},
+    },
+};
+use std::collections::HashMap;
+
+impl OneInchClient {
+    /// Performs request to get balances of all the tokens of the wallet.
+    pub async fn get_balances(&self, wallet: Address) -> Result<TokenBalances, OneInchError> {
+        let url = format!(
+            "{}/balance/{}/{}/balances/{:#x}",
+            self.base_url, BALANCE_API_VERSION, self.network_id, wallet
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get balances of the specified tokens of the wallet.
+    pub async fn get_custom_balances(&self, wallet: Address, tokens: &[Address]) -> Result<TokenBalances, OneInchError> {
+        let url = format!(
+            "{}/balance/{}/{}/balances/{:#x}",
+            self.base_url, BALANCE_API_VERSION, self.network_id, wallet
+        );
+
+        self.post_json(parse_url(&url)?, &TokensRequest { tokens }).await
+    }
+
+    /// Performs request to get allowances of all the tokens of the wallet to
+    /// the spender.
+    pub async fn get_allowances(&self, spender: Address, wallet: Address) -> Result<TokenBalances, OneInchError> {
+        let url = format!(
+            "{}/balance/{}/{}/allowances/{:#x}/{:#x}",
+            self.base_url, BALANCE_API_VERSION, self.network_id, spender, wallet
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get balances of the wallet tokens along with their
+    /// allowances to the spender.
+    pub async fn get_allowances_and_balances(
+        &self,
+        spender: Address,
+        wallet: Address,
+        fetch_type: Option<TokensFetchType>,
+    ) -> Result<HashMap<Address, BalanceAndAllowance>, OneInchError> {
+        let url = format!(
+            "{}/balance/{}/{}/allowancesAndBalances/{:#x}/{:#x}",
+            self.base_url, BALANCE_API_VERSION, self.network_id, spender, wallet
+        );
+
+        let mut params = vec![];
+        insert_optional_param(&mut params, "tokensFetchType", fetch_type.map(|t| t.to_string()));
+
+        self.get_json(parse_url_with_params(&url, params)?).await
+    }
+
+    /// Performs request to get balances of the specified tokens of the wallet
+    /// along with their allowances to the spender.
+    pub async fn get_custom_allowances_and_balances(
+        &self,
+        spender: Address,
+        wallet: Address,
+        tokens: &[Address],
+    ) -> Result<HashMap<Address, BalanceAndAllowance>, OneInchError> {
+        let url = format!(
+            "{}/balance/{}/{}/allowancesAndBalances/{:#x}/{:#x}",
+            self.base_url, BALANCE_API_VERSION, self.network_id, spender, wallet
+        );
+
+        self.post_json(parse_url(&url)?, &TokensRequest { tokens }).await
+    }
+
+    /// Performs request to get balances and allowances to the spender of
+    /// multiple wallets, aggregated by token. Tokens no wallet holds are
+    /// skipped if `filter_empty` is set.
+    pub async fn get_aggregated_balances_and_allowances(
+        &self,
+        spender: Address,
+        wallets: &[Address],
+        filter_empty: bool,
+    ) -> Result<Vec<AggregatedBalance>, OneInchError> {
+        let url = format!(
+            "{}/balance/{}/{}/aggregatedBalancesAndAllowances/{:#x}",
+            self.base_url, BALANCE_API_VERSION, self.network_id, spender
+        );
+
+        let wallets = wallets.iter().map(|wallet| format!("{:#x}", wallet)).collect::<Vec<String>>().join(",");
+        let params = vec![("wallets", wallets), ("filterEmpty", filter_empty.to_string())];
+
+        self.get_json(parse_url_with_params(&url, params)?).await
+    }
+
+    /// Performs request to get balances of the specified tokens of multiple
+    /// wallets, keyed by the wallet address.
+    pub async fn get_balances_of_wallets(
+        &self,
+        wallets: &[Address],
+        tokens: &[Address],
+    ) -> Result<HashMap<Address, TokenBalances>, OneInchError> {
+        let url = format!(
+            "{}/balance/{}/{}/balances/multiple/walletsAndTokens",
+            self.base_url, BALANCE_API_VERSION, self.network_id
+        );
+
+        self.post_json(parse_url(&url)?, &WalletsAndTokensRequest { wallets, tokens }).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{testing::*, Mock, MockTransport};
+
+    #[tokio::test]
+    async fn test_balance_with_mock() {
+        let transport = MockTransport::new();
+        transport
+            .register(Mock::post(format!("/balance/v1.2/56/balances/{}", WALLET)).json_body(format!(r#"{{"{}":"1000"}}"#, USDT)))
+            .register(
+                Mock::get(format!("/balance/v1.2/56/allowancesAndBalances/{}/{}", XRP, WALLET))
+                    .query_param("tokensFetchType", "listedTokens")
+                    .json_body(format!(r#"{{"{}":{{"balance":"5","allowance":"0"}}}}"#, USDT)),
+            );
+
+        let client = client_with(&transport);
+        let usdt: Address = USDT.parse().unwrap();
+
+        let balances = client.get_custom_balances(WALLET.parse().unwrap(), &[usdt]).await.unwrap();
+        assert_eq!(balances[&usdt], 1000u64.into());
+
+        let allowances = client
+            .get_allowances_and_balances(XRP.parse().unwrap(), WALLET.parse().unwrap(), Some(TokensFetchType::ListedTokens))
+            .await
+            .unwrap();
+        assert!(allowances[&usdt].allowance.is_zero());
+
+        let body: serde_json::Value = serde_json::from_slice(transport.requests()[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["tokens"][0].as_str().unwrap().to_lowercase(), USDT);
+    }
+}
@@ -0,0 +1,4 @@
+mod balances;
+mod types;
+
+pub use types::*;
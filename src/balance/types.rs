@@ -0,0 +1,85 @@
+use crate::common::{address::Address, amount::TokenAmount};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum_macros::Display;
+
+/// Amounts keyed by the token address. The native token of the network is
+/// keyed by [`Address::NATIVE_TOKEN`].
+pub type TokenBalances = HashMap<Address, TokenAmount>;
+
+/// Balance of the token and its allowance to the spender.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BalanceAndAllowance {
+    pub balance: TokenAmount,
+    pub allowance: TokenAmount,
+}
+
+/// Tokens the balances and allowances are fetched for.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+pub enum TokensFetchType {
+    /// Only the tokens listed by 1inch.
+    ListedTokens,
+
+    /// Every token the wallet interacted with.
+    AllTokens,
+}
+
+/// Token with balances and allowances of each of the requested wallets.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregatedBalance {
+    pub address: Address,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+
+    #[serde(rename = "logoURI")]
+    pub logo_uri: Option<String>,
+
+    #[serde(rename = "type")]
+    pub token_type: String,
+    pub tracked: bool,
+    pub wallets: HashMap<Address, BalanceAndAllowance>,
+}
+
+// Body of the requests for balances of the specified tokens.
+#[derive(Serialize, Debug)]
+pub(crate) struct TokensRequest<'a> {
+    pub tokens: &'a [Address],
+}
+
+// Body of the request for balances of multiple wallets.
+#[derive(Serialize, Debug)]
+pub(crate) struct WalletsAndTokensRequest<'a> {
+    pub wallets: &'a [Address],
+    pub tokens: &'a [Address],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregated_balance_deserialization() {
+        let json = r#"[{
+            "address": "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+            "name": "BNB",
+            "symbol": "BNB",
+            "decimals": 18,
+            "logoURI": null,
+            "type": "native",
+            "tracked": true,
+            "wallets": {
+                "0x13961a09bcd42dcc078765286be746d87f20e82e": { "balance": "1000", "allowance": "0" }
+            }
+        }]"#;
+
+        let balances: Vec<AggregatedBalance> = serde_json::from_str(json).unwrap();
+        assert!(balances[0].address.is_native_token());
+
+        let wallet = "0x13961a09bcd42dcc078765286be746d87f20e82e".parse().unwrap();
+        assert_eq!(balances[0].wallets[&wallet].balance, 1000u64.into());
+        assert_eq!(TokensFetchType::ListedTokens.to_string(), "listedTokens");
+    }
+}
@@ -1,50 +1,270 @@
-use core::fmt;
-use strum_macros::{Display, FromRepr};
+pub use crate::common::network::SupportedNetworks;
 
-/// Struct to work with 1inch api
+use crate::{
+    builder_setter,
+    consts::BASIC_URL,
+    error::OneInchError,
+    rate_limit::RateLimiter,
+    retry::RetryPolicy,
+    swap::SwapApiVersion,
+    transport::{ReqwestTransport, Transport},
+};
+use futures::future::join_all;
+use reqwest::Url;
+use std::{future::Future, sync::Arc, time::Duration};
+use strum_macros::Display;
+use thiserror::Error;
+
+/// Struct to work with 1inch api.
+///
+/// The client is cheap to clone: all the clones share the same transport
+/// (and so the connection pool) and rate limiter.
+#[derive(Clone, Debug)]
 pub struct OneInchClient {
-    /// reqwest::Client does not need to be Rc/Arc because it already uses an
-    /// Arc internally.
-    pub http_client: reqwest::Client,
+    /// Transport used to send requests, [`ReqwestTransport`] by default.
+    pub(crate) transport: Arc<dyn Transport>,
 
     /// An authorization token for interacting with API.
     /// There you can get your own token : <https://portal.1inch.dev/applications>
     pub(crate) token: String,
 
-    /// The ID of the network on which you want to work.
-    /// You can interact only with 1 specified network with your client.
+    /// The ID of the network requests are sent to. Use
+    /// [`OneInchClient::on_chain`] to interact with other networks using the
+    /// same client.
     pub(crate) network_id: SupportedNetworks,
+
+    /// Base url every endpoint is resolved against, without a trailing slash.
+    /// Defaults to the public 1inch gateway.
+    pub(crate) base_url: String,
+
+    /// Version of the swap API used by `swap/*` endpoints.
+    pub(crate) swap_api_version: SwapApiVersion,
+
+    /// Policy used to retry failed requests. `None` means requests are sent
+    /// only once.
+    pub(crate) retry_policy: Option<RetryPolicy>,
+
+    /// Limiter every request waits for before being sent. `None` means
+    /// requests are not limited on the client side.
+    pub(crate) rate_limiter: Option<RateLimiter>,
+}
+
+impl OneInchClient {
+    /// Creates a [`OneInchClientBuilder`] to configure a new client.
+    pub fn builder() -> OneInchClientBuilder {
+        OneInchClientBuilder::new()
+    }
+
+    /// Returns the base url the client sends its requests to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the network requests are sent to.
+    pub fn network_id(&self) -> SupportedNetworks {
+        self.network_id
+    }
+
+    /// Returns a client sending requests to the specified network.
+    ///
+    /// The returned client shares the transport, rate limiter, retry policy
+    /// and token with this one, so it's cheap to create one per call:
+    ///
+    /// ```no_run
+    /// # use one_inch::client::{OneInchClient, SupportedNetworks};
+    /// # async fn run(client: OneInchClient) {
+    /// let polygon_tokens = client.on_chain(SupportedNetworks::Polygon).get_tokens_list().await;
+    /// # }
+    /// ```
+    pub fn on_chain(&self, network_id: SupportedNetworks) -> OneInchClient {
+        OneInchClient { network_id, ..self.clone() }
+    }
+
+    /// Performs the same request on several networks concurrently and returns
+    /// results in the order networks were passed.
+    ///
+    /// `request` receives a client bound to each of the networks, see
+    /// [`OneInchClient::on_chain`].
+    ///
+    /// ```no_run
+    /// # use one_inch::client::{OneInchClient, SupportedNetworks};
+    /// # async fn run(client: OneInchClient) {
+    /// let networks = [SupportedNetworks::Ethereum, SupportedNetworks::BSC];
+    /// let results = client.fan_out(networks, |client| async move { client.get_liquidity_sources().await }).await;
+    /// # }
+    /// ```
+    pub async fn fan_out<T, F, Fut>(
+        &self,
+        networks: impl IntoIterator<Item = SupportedNetworks>,
+        request: F,
+    ) -> Vec<(SupportedNetworks, Result<T, OneInchError>)>
+    where
+        F: Fn(OneInchClient) -> Fut,
+        Fut: Future<Output = Result<T, OneInchError>>,
+    {
+        let requests = networks.into_iter().map(|network_id| {
+            let response = request(self.on_chain(network_id));
+            async move { (network_id, response.await) }
+        });
+
+        join_all(requests).await
+    }
+
+    /// Returns the version of the swap API used by the client.
+    pub fn swap_api_version(&self) -> SwapApiVersion {
+        self.swap_api_version
+    }
+
+    /// Returns the transport the client sends its requests with.
+    pub fn transport(&self) -> &Arc<dyn Transport> {
+        &self.transport
+    }
+
+    /// Returns the retry policy used by the client, if any.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Returns the rate limiter used by the client, if any.
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
 }
 
-/// Function creates a OneInchClient instance with default http settings.
-pub fn new_with_default_http(token: String, network_id: SupportedNetworks) -> OneInchClient {
-    OneInchClient { http_client: reqwest::Client::default(), token, network_id }
+/// Enumerates potential errors when constructing `OneInchClient`.
+#[derive(Error, Debug)]
+pub enum OneInchClientBuilderError {
+    /// Indicates a required field is missing its value.
+    #[error("Missing {0}")]
+    MissingField(&'static str),
+
+    /// Indicates the provided base url can't be used to build endpoints urls.
+    #[error("Invalid base url: {0}")]
+    InvalidBaseUrl(String),
+
+    /// Indicates http options were set together with a pre-built
+    /// `reqwest::Client` or a custom transport, which can't be reconfigured.
+    #[error("Option `{0}` can't be applied to a pre-built http client or a custom transport")]
+    ConflictingHttpOption(&'static str),
+
+    /// Indicates the underlying `reqwest::Client` could not be created.
+    #[error("Failed to build http client: {0}")]
+    HttpClient(reqwest::Error),
 }
 
-/// List of all supported Networks/Chains with their IDs.
-#[derive(FromRepr, Debug, Copy, Clone)]
-#[repr(u32)]
-pub enum SupportedNetworks {
-    Ethereum = 1,
-    Optimism = 10,
-    BSC = 56,
-    Gnosis = 100,
-    Polygon = 137,
-    Fantom = 250,
-    ZkSync = 324,
-    Klaytn = 8217,
-    Base = 8453,
-    Arbitrum = 42161,
-    Avalanche = 43114,
-    Aurora = 1313161554,
+/// A builder pattern implementation for creating a `OneInchClient`.
+///
+/// Only `token` is required, `network_id` defaults to
+/// [`SupportedNetworks::Ethereum`]. Timeouts and user agent are
+/// applied to a newly created `reqwest::Client`, so they can't be combined
+/// with `http_client` or `transport`.
+#[derive(Default)]
+pub struct OneInchClientBuilder {
+    token: Option<String>,
+    network_id: Option<SupportedNetworks>,
+    base_url: Option<String>,
+    swap_api_version: Option<SwapApiVersion>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+
+    // Http settings
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    http_client: Option<reqwest::Client>,
+    transport: Option<Arc<dyn Transport>>,
 }
 
-impl fmt::Display for SupportedNetworks {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", *self as u32)
+impl OneInchClientBuilder {
+    /// Constructs a new `OneInchClientBuilder` with all fields uninitialized.
+    pub fn new() -> Self {
+        OneInchClientBuilder::default()
     }
-}
 
+    builder_setter!(token, String);
+    builder_setter!(network_id, SupportedNetworks);
+    builder_setter!(base_url, String);
+    builder_setter!(swap_api_version, SwapApiVersion);
+    builder_setter!(retry_policy, RetryPolicy);
+    builder_setter!(rate_limiter, RateLimiter);
+
+    builder_setter!(timeout, Duration);
+    builder_setter!(connect_timeout, Duration);
+    builder_setter!(user_agent, String);
+    builder_setter!(http_client, reqwest::Client);
+
+    /// Sets a custom transport, e.g. [`MockTransport`](crate::transport::MockTransport)
+    /// for offline testing.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Attempts to construct a [`OneInchClient`] from the builder, returning
+    /// errors if required fields are missing or if some of values are
+    /// incorrect.
+    pub fn build(self) -> Result<OneInchClient, OneInchClientBuilderError> {
+        let token = self.token.ok_or(OneInchClientBuilderError::MissingField("token"))?;
+        let network_id = self.network_id.unwrap_or(SupportedNetworks::Ethereum);
+
+        let base_url = self.base_url.unwrap_or_else(|| BASIC_URL.to_string());
+        let base_url = base_url.trim_end_matches('/').to_string();
+
+        match Url::parse(&base_url) {
+            Ok(url) if !url.cannot_be_a_base() => {}
+            _ => return Err(OneInchClientBuilderError::InvalidBaseUrl(base_url)),
+        }
+
+        let prebuilt_transport: Option<Arc<dyn Transport>> = match (self.transport, self.http_client) {
+            (Some(_), Some(_)) => return Err(OneInchClientBuilderError::ConflictingHttpOption("http_client")),
+            (Some(transport), None) => Some(transport),
+            (None, Some(http_client)) => Some(Arc::new(ReqwestTransport::new(http_client))),
+            (None, None) => None,
+        };
+
+        let transport = match prebuilt_transport {
+            Some(transport) => {
+                if self.timeout.is_some() {
+                    return Err(OneInchClientBuilderError::ConflictingHttpOption("timeout"));
+                }
+                if self.connect_timeout.is_some() {
+                    return Err(OneInchClientBuilderError::ConflictingHttpOption("connect_timeout"));
+                }
+                if self.user_agent.is_some() {
+                    return Err(OneInchClientBuilderError::ConflictingHttpOption("user_agent"));
+                }
+                transport
+            }
+            None => {
+                let mut http_builder = reqwest::Client::builder();
+
+                if let Some(timeout) = self.timeout {
+                    http_builder = http_builder.timeout(timeout);
+                }
+                if let Some(connect_timeout) = self.connect_timeout {
+                    http_builder = http_builder.connect_timeout(connect_timeout);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    http_builder = http_builder.user_agent(user_agent);
+                }
+
+                Arc::new(ReqwestTransport::new(
+                    http_builder.build().map_err(OneInchClientBuilderError::HttpClient)?,
+                ))
+            }
+        };
+
+        Ok(OneInchClient {
+            transport,
+            token,
+            network_id,
+            base_url,
+            swap_api_version: self.swap_api_version.unwrap_or_default(),
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limiter,
+        })
+    }
+}
 /// List of all supported currencies in 1inch.
 #[derive(Debug, Display, Clone)]
 pub enum SupportedCurrencies {
@@ -94,3 +314,76 @@ pub enum SupportedCurrencies {
     VND,
     ZAR,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{testing::*, Mock, MockTransport};
+
+    #[test]
+    fn test_client_builder_defaults() {
+        let client = OneInchClientBuilder::new()
+            .token("token".to_string())
+            .network_id(SupportedNetworks::BSC)
+            .build()
+            .expect("Failed to build OneInchClient");
+
+        assert_eq!(client.base_url(), BASIC_URL);
+        assert_eq!(client.network_id() as u32, 56);
+    }
+
+    #[test]
+    fn test_client_builder_custom_base_url() {
+        let client = OneInchClientBuilder::new()
+            .token("token".to_string())
+            .network_id(SupportedNetworks::Ethereum)
+            .base_url("http://localhost:8080/1inch/".to_string())
+            .timeout(Duration::from_secs(10))
+            .user_agent("my-bot".to_string())
+            .build()
+            .expect("Failed to build OneInchClient");
+
+        assert_eq!(client.base_url(), "http://localhost:8080/1inch");
+    }
+
+    #[test]
+    fn test_client_builder_errors() {
+        let missing_token = OneInchClientBuilder::new().network_id(SupportedNetworks::Ethereum).build();
+        assert!(matches!(missing_token, Err(OneInchClientBuilderError::MissingField("token"))));
+
+        let without_network = OneInchClientBuilder::new().token("token".to_string()).build().unwrap();
+        assert_eq!(without_network.network_id() as u32, 1);
+
+        let invalid_url = OneInchClientBuilder::new()
+            .token("token".to_string())
+            .network_id(SupportedNetworks::Ethereum)
+            .base_url("not a url".to_string())
+            .build();
+        assert!(matches!(invalid_url, Err(OneInchClientBuilderError::InvalidBaseUrl(_))));
+
+        let conflicting = OneInchClientBuilder::new()
+            .token("token".to_string())
+            .network_id(SupportedNetworks::Ethereum)
+            .http_client(reqwest::Client::new())
+            .timeout(Duration::from_secs(1))
+            .build();
+        assert!(matches!(conflicting, Err(OneInchClientBuilderError::ConflictingHttpOption("timeout"))));
+    }
+
+    #[tokio::test]
+    async fn test_fan_out_with_mock() {
+        let transport = MockTransport::new();
+        transport
+            .register(Mock::get("/swap/v5.2/1/liquidity-sources").json_body(r#"{"protocols":[]}"#))
+            .register(Mock::get("/swap/v5.2/56/liquidity-sources").status(500));
+
+        let networks = [SupportedNetworks::Ethereum, SupportedNetworks::BSC];
+        let results =
+            client_with(&transport).fan_out(networks, |client| async move { client.get_liquidity_sources().await }).await;
+
+        assert_eq!(results[0].0, SupportedNetworks::Ethereum);
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, SupportedNetworks::BSC);
+        assert_eq!(results[1].1.as_ref().unwrap_err().status(), Some(500));
+    }
+}
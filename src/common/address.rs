@@ -0,0 +1,244 @@
+use crate::utils::keccak::keccak256;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// 20-byte Ethereum address.
+///
+/// Parsing accepts lowercase and uppercase hex as is, while mixed-case input
+/// must carry a valid [EIP-55](https://eips.ethereum.org/EIPS/eip-55)
+/// checksum, so typos are caught before a request is sent. `Display` and
+/// `Serialize` produce the checksummed form, `{:#x}` produces the lowercase
+/// form which is used in request urls.
+///
+/// ```
+/// use one_inch::common::address::Address;
+///
+/// let usdt: Address = "0xdac17f958d2ee523a2206206994597c13d831ec7".parse().unwrap();
+/// assert_eq!(usdt.to_string(), "0xdAC17F958D2ee523a2206206994597C13D831ec7");
+///
+/// // Wrong checksum
+/// assert!("0xdAC17F958D2ee523a2206206994597C13D831Ec7".parse::<Address>().is_err());
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Address([u8; 20]);
+
+/// Enumerates potential errors when parsing `Address`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum AddressError {
+    /// Indicates the address doesn't consist of 40 hex characters.
+    #[error("Invalid address length: expected 40 hex characters, got {0}")]
+    InvalidLength(usize),
+
+    /// Indicates the address contains non-hex characters.
+    #[error("Invalid hex character in address")]
+    InvalidHex,
+
+    /// Indicates the mixed-case address doesn't match its EIP-55 checksum.
+    #[error("Invalid EIP-55 checksum, expected {0}")]
+    InvalidChecksum(String),
+}
+
+impl Address {
+    /// Zero address `0x0000000000000000000000000000000000000000`.
+    pub const ZERO: Address = Address([0u8; 20]);
+
+    /// Placeholder address 1inch uses for the native token of the network
+    /// (ETH, BNB, MATIC, ...): `0xEeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE`.
+    pub const NATIVE_TOKEN: Address = Address([0xee; 20]);
+
+    /// Creates an address from raw bytes.
+    pub const fn from_bytes(bytes: [u8; 20]) -> Self {
+        Address(bytes)
+    }
+
+    /// Returns raw bytes of the address.
+    pub const fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+
+    /// Returns whether the address is the native token placeholder.
+    pub fn is_native_token(&self) -> bool {
+        *self == Address::NATIVE_TOKEN
+    }
+
+    /// Returns EIP-55 checksummed representation of the address.
+    pub fn to_checksum(&self) -> String {
+        let lowercase = encode_hex(&self.0);
+        let hash = keccak256(lowercase.as_bytes());
+
+        let checksummed: String = lowercase
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+                if nibble >= 8 {
+                    c.to_ascii_uppercase()
+                } else {
+                    c
+                }
+            })
+            .collect();
+
+        format!("0x{}", checksummed)
+    }
+
+    /// Parses the address requiring valid EIP-55 checksum even if the input is
+    /// all lowercase or uppercase.
+    pub fn parse_checksummed(s: &str) -> Result<Self, AddressError> {
+        let address = Address::from_str(s)?;
+        let checksummed = address.to_checksum();
+
+        if checksummed[2..] != *strip_prefix(s) {
+            return Err(AddressError::InvalidChecksum(checksummed));
+        }
+
+        Ok(address)
+    }
+}
+
+impl FromStr for Address {
+    type Err = AddressError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = strip_prefix(s);
+
+        if hex.len() != 40 {
+            return Err(AddressError::InvalidLength(hex.len()));
+        }
+
+        // `from_str_radix` alone would accept signs, like `+1`.
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(AddressError::InvalidHex);
+        }
+
+        let mut bytes = [0u8; 20];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(hex.get(i * 2..i * 2 + 2).ok_or(AddressError::InvalidHex)?, 16)
+                .map_err(|_| AddressError::InvalidHex)?;
+        }
+
+        let address = Address(bytes);
+
+        let has_lowercase = hex.chars().any(|c| c.is_ascii_lowercase());
+        let has_uppercase = hex.chars().any(|c| c.is_ascii_uppercase());
+
+        if has_lowercase && has_uppercase {
+            let checksummed = address.to_checksum();
+            if checksummed[2..] != *hex {
+                return Err(AddressError::InvalidChecksum(checksummed));
+            }
+        }
+
+        Ok(address)
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_checksum())
+    }
+}
+
+impl fmt::Debug for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Address({})", self.to_checksum())
+    }
+}
+
+impl fmt::LowerHex for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        f.write_str(&encode_hex(&self.0))
+    }
+}
+
+impl From<[u8; 20]> for Address {
+    fn from(bytes: [u8; 20]) -> Self {
+        Address(bytes)
+    }
+}
+
+impl From<Address> for [u8; 20] {
+    fn from(address: Address) -> Self {
+        address.0
+    }
+}
+
+impl Serialize for Address {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_checksum())
+    }
+}
+
+impl<'de> Deserialize<'de> for Address {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Address::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+fn strip_prefix(s: &str) -> &str {
+    s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s)
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vectors from EIP-55
+    const CHECKSUMMED: [&str; 4] = [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+
+    #[test]
+    fn test_eip55_checksum() {
+        for checksummed in CHECKSUMMED {
+            let address: Address = checksummed.to_lowercase().parse().unwrap();
+            assert_eq!(address.to_checksum(), checksummed);
+            assert_eq!(Address::parse_checksummed(checksummed).unwrap(), address);
+        }
+    }
+
+    #[test]
+    fn test_invalid_addresses() {
+        assert_eq!(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".parse::<Address>(),
+            Err(AddressError::InvalidChecksum(CHECKSUMMED[0].into()))
+        );
+        assert_eq!(
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beae".parse::<Address>(),
+            Err(AddressError::InvalidLength(39))
+        );
+        assert_eq!(
+            "0xzaaeb6053f3e94c9b9a09f33669435e7ef1beaed".parse::<Address>(),
+            Err(AddressError::InvalidHex)
+        );
+        assert_eq!(format!("0x+1{}", "0".repeat(38)).parse::<Address>(), Err(AddressError::InvalidHex));
+        assert_eq!(format!("0x{}-1", "0".repeat(38)).parse::<Address>(), Err(AddressError::InvalidHex));
+        assert!(Address::parse_checksummed("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").is_err());
+    }
+
+    #[test]
+    fn test_native_token_and_formatting() {
+        let native: Address = "0xEeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE".parse().unwrap();
+        assert_eq!(native, Address::NATIVE_TOKEN);
+        assert!(native.is_native_token());
+        assert_eq!(format!("{:#x}", native), "0xeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee");
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let address: Address = serde_json::from_str("\"0xd1220a0cf47c7b9be7a2e6ba89f429762e7b9adb\"").unwrap();
+        assert_eq!(serde_json::to_string(&address).unwrap(), format!("\"{}\"", CHECKSUMMED[3]));
+    }
+}
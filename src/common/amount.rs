@@ -0,0 +1,287 @@
+use num_bigint::BigUint;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// Amount of a token in its smallest units (wei for ETH, 10^-6 USDT for USDT,
+/// etc), backed by an unsigned 256-bit integer like `uint256` on chain.
+///
+/// The API returns amounts as decimal strings, which are parsed with
+/// `FromStr`/`Deserialize`. Human readable values are converted with
+/// [`TokenAmount::from_units`] and [`TokenAmount::to_units`] using the token's
+/// decimals.
+///
+/// ```
+/// use one_inch::common::amount::TokenAmount;
+///
+/// let amount = TokenAmount::from_units("1.5", 6).unwrap();
+/// assert_eq!(amount.to_string(), "1500000");
+/// assert_eq!(amount.to_units(6), "1.5");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TokenAmount(BigUint);
+
+/// Enumerates potential errors when creating `TokenAmount`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum TokenAmountError {
+    /// Indicates the input is not a valid non-negative number.
+    #[error("Invalid amount: {0}")]
+    InvalidNumber(String),
+
+    /// Indicates the value doesn't fit into 256 bits.
+    #[error("Amount doesn't fit into 256 bits")]
+    Overflow,
+
+    /// Indicates the human readable value has more fractional digits than the
+    /// token supports.
+    #[error("Amount has more than {0} fractional digits")]
+    TooManyDecimals(u8),
+}
+
+impl TokenAmount {
+    /// Maximal amount of bits the value can take.
+    pub const BITS: u64 = 256;
+
+    /// Returns zero amount.
+    pub fn zero() -> Self {
+        TokenAmount(BigUint::default())
+    }
+
+    /// Returns the biggest possible amount, `2^256 - 1`. It's commonly used as
+    /// an "infinite" allowance.
+    pub fn max() -> Self {
+        TokenAmount((BigUint::from(1u8) << Self::BITS) - 1u8)
+    }
+
+    /// Creates an amount from `BigUint`, returning error if it doesn't fit into
+    /// 256 bits.
+    pub fn from_biguint(value: BigUint) -> Result<Self, TokenAmountError> {
+        if value.bits() > Self::BITS {
+            return Err(TokenAmountError::Overflow);
+        }
+        Ok(TokenAmount(value))
+    }
+
+    /// Returns the underlying integer.
+    pub fn as_biguint(&self) -> &BigUint {
+        &self.0
+    }
+
+    /// Returns whether the amount is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.bits() == 0
+    }
+
+    /// Returns 32-byte big-endian representation of the amount, as it's
+    /// encoded on chain.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let bytes = self.0.to_bytes_be();
+        let mut output = [0u8; 32];
+        output[32 - bytes.len()..].copy_from_slice(&bytes);
+        output
+    }
+
+    /// Converts human readable value like `"1.5"` into smallest units of a
+    /// token with specified decimals. Values with more fractional digits than
+    /// `decimals` are rejected instead of being rounded.
+    pub fn from_units(value: &str, decimals: u8) -> Result<Self, TokenAmountError> {
+        let invalid = || TokenAmountError::InvalidNumber(value.to_string());
+
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) || (value.contains('.') && fraction.is_empty()) {
+            return Err(invalid());
+        }
+
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > decimals as usize {
+            return Err(TokenAmountError::TooManyDecimals(decimals));
+        }
+
+        let digits = format!("{}{}{}", whole, fraction, "0".repeat(decimals as usize - fraction.len()));
+        let units = BigUint::parse_bytes(digits.as_bytes(), 10).ok_or_else(invalid)?;
+
+        TokenAmount::from_biguint(units)
+    }
+
+    /// Converts the amount into human readable value of a token with specified
+    /// decimals, without trailing zeros: `1500000` with 6 decimals is `"1.5"`.
+    pub fn to_units(&self, decimals: u8) -> String {
+        let digits = self.0.to_str_radix(10);
+        let decimals = decimals as usize;
+
+        if decimals == 0 {
+            return digits;
+        }
+
+        let padded = format!("{:0>width$}", digits, width = decimals + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            whole.to_string()
+        } else {
+            format!("{}.{}", whole, fraction)
+        }
+    }
+
+    /// Checked addition. Returns `None` if the result overflows 256 bits.
+    pub fn checked_add(&self, other: &TokenAmount) -> Option<TokenAmount> {
+        TokenAmount::from_biguint(&self.0 + &other.0).ok()
+    }
+
+    /// Checked subtraction. Returns `None` if the result is negative.
+    pub fn checked_sub(&self, other: &TokenAmount) -> Option<TokenAmount> {
+        (self.0 >= other.0).then(|| TokenAmount(&self.0 - &other.0))
+    }
+
+    /// Checked multiplication. Returns `None` if the result overflows 256
+    /// bits.
+    pub fn checked_mul(&self, other: &TokenAmount) -> Option<TokenAmount> {
+        TokenAmount::from_biguint(&self.0 * &other.0).ok()
+    }
+
+    /// Checked integer division. Returns `None` if `other` is zero.
+    pub fn checked_div(&self, other: &TokenAmount) -> Option<TokenAmount> {
+        (!other.is_zero()).then(|| TokenAmount(&self.0 / &other.0))
+    }
+}
+
+impl FromStr for TokenAmount {
+    type Err = TokenAmountError;
+
+    /// Parses decimal string of smallest units, as the API returns amounts.
+    /// `0x`-prefixed hex strings are accepted too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TokenAmountError::InvalidNumber(s.to_string());
+
+        let value = match s.strip_prefix("0x") {
+            Some(hex) if !hex.is_empty() && hex.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                BigUint::parse_bytes(hex.as_bytes(), 16)
+            }
+            Some(_) => None,
+            None if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) => BigUint::parse_bytes(s.as_bytes(), 10),
+            None => None,
+        };
+
+        TokenAmount::from_biguint(value.ok_or_else(invalid)?)
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Debug for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TokenAmount({})", self.0)
+    }
+}
+
+impl From<u64> for TokenAmount {
+    fn from(value: u64) -> Self {
+        TokenAmount(BigUint::from(value))
+    }
+}
+
+impl From<u128> for TokenAmount {
+    fn from(value: u128) -> Self {
+        TokenAmount(BigUint::from(value))
+    }
+}
+
+impl TryFrom<BigUint> for TokenAmount {
+    type Error = TokenAmountError;
+
+    fn try_from(value: BigUint) -> Result<Self, Self::Error> {
+        TokenAmount::from_biguint(value)
+    }
+}
+
+impl Serialize for TokenAmount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_str_radix(10))
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenAmount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Amounts usually come as strings, but small ones are sometimes
+        // returned as JSON numbers.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum RawAmount {
+            String(String),
+            Number(u64),
+        }
+
+        match RawAmount::deserialize(deserializer)? {
+            RawAmount::String(s) => TokenAmount::from_str(&s).map_err(de::Error::custom),
+            RawAmount::Number(n) => Ok(TokenAmount::from(n)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units_conversion() {
+        assert_eq!(TokenAmount::from_units("1.5", 6).unwrap(), TokenAmount::from(1_500_000u64));
+        assert_eq!(TokenAmount::from_units("0.000001", 6).unwrap(), TokenAmount::from(1u64));
+        assert_eq!(TokenAmount::from_units("2", 18).unwrap().to_string(), "2000000000000000000");
+        assert_eq!(TokenAmount::from_units("1.10", 1).unwrap(), TokenAmount::from(11u64));
+
+        assert_eq!(TokenAmount::from(1_500_000u64).to_units(6), "1.5");
+        assert_eq!(TokenAmount::from(5u64).to_units(6), "0.000005");
+        assert_eq!(TokenAmount::from(1_000_000u64).to_units(6), "1");
+        assert_eq!(TokenAmount::from(42u64).to_units(0), "42");
+    }
+
+    #[test]
+    fn test_invalid_amounts() {
+        assert_eq!(TokenAmount::from_units("1.0000001", 6), Err(TokenAmountError::TooManyDecimals(6)));
+        assert!(TokenAmount::from_units("-1", 6).is_err());
+        assert!(TokenAmount::from_units("1.", 6).is_err());
+        assert!(TokenAmount::from_units(".5", 6).is_err());
+        assert!("1e18".parse::<TokenAmount>().is_err());
+        assert!("".parse::<TokenAmount>().is_err());
+        assert!("+1".parse::<TokenAmount>().is_err());
+        assert!("0x+1".parse::<TokenAmount>().is_err());
+        assert!("0x1_0".parse::<TokenAmount>().is_err());
+        assert_eq!("0xff".parse::<TokenAmount>().unwrap(), 255u64.into());
+
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(max.parse::<TokenAmount>().unwrap(), TokenAmount::max());
+        assert_eq!(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936".parse::<TokenAmount>(),
+            Err(TokenAmountError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let one = TokenAmount::from(1u64);
+        let two = TokenAmount::from(2u64);
+
+        assert_eq!(one.checked_add(&one), Some(two.clone()));
+        assert_eq!(TokenAmount::max().checked_add(&one), None);
+        assert_eq!(one.checked_sub(&two), None);
+        assert_eq!(two.checked_mul(&two), Some(TokenAmount::from(4u64)));
+        assert_eq!(TokenAmount::max().checked_mul(&two), None);
+        assert_eq!(two.checked_div(&TokenAmount::zero()), None);
+    }
+
+    #[test]
+    fn test_serde() {
+        let amount: TokenAmount = serde_json::from_str("\"1000000000000000000\"").unwrap();
+        assert_eq!(amount, TokenAmount::from(1_000_000_000_000_000_000u64));
+        assert_eq!(serde_json::from_str::<TokenAmount>("0").unwrap(), TokenAmount::zero());
+        assert_eq!(serde_json::to_string(&amount).unwrap(), "\"1000000000000000000\"");
+        assert_eq!(amount.to_be_bytes()[24..], 1_000_000_000_000_000_000u64.to_be_bytes());
+    }
+}
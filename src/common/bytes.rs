@@ -0,0 +1,140 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, ops::Deref, str::FromStr};
+use thiserror::Error;
+
+/// Arbitrary binary data like calldata, signatures or order extensions.
+///
+/// The API passes such data as `0x`-prefixed hex strings, so `FromStr`,
+/// `Display` and serde all work with this form.
+///
+/// ```
+/// use one_inch::common::bytes::Bytes;
+///
+/// let data: Bytes = "0x095ea7b3".parse().unwrap();
+/// assert_eq!(data.as_ref(), &[0x09, 0x5e, 0xa7, 0xb3]);
+/// assert_eq!(data.to_string(), "0x095ea7b3");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bytes(Vec<u8>);
+
+/// Enumerates potential errors when parsing `Bytes`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum BytesError {
+    /// Indicates the hex string has an odd number of characters.
+    #[error("Odd number of hex characters")]
+    OddLength,
+
+    /// Indicates the string contains non-hex characters.
+    #[error("Invalid hex character in bytes")]
+    InvalidHex,
+}
+
+impl Bytes {
+    /// Creates empty `Bytes`.
+    pub fn new() -> Self {
+        Bytes(Vec::new())
+    }
+
+    /// Consumes `Bytes` returning the underlying vector.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for Bytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for Bytes {
+    fn from(bytes: &[u8]) -> Self {
+        Bytes(bytes.to_vec())
+    }
+}
+
+impl FromStr for Bytes {
+    type Err = BytesError;
+
+    /// Parses hex string, with or without `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+
+        // `from_str_radix` alone would accept signs, like `+1`.
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(BytesError::InvalidHex);
+        }
+
+        if !hex.len().is_multiple_of(2) {
+            return Err(BytesError::OddLength);
+        }
+
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| BytesError::InvalidHex))
+            .collect::<Result<Vec<u8>, _>>()
+            .map(Bytes)
+    }
+}
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("0x")?;
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl fmt::Debug for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bytes({})", self)
+    }
+}
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Bytes::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_parsing() {
+        assert_eq!("0x".parse::<Bytes>().unwrap(), Bytes::new());
+        assert_eq!("00ff".parse::<Bytes>().unwrap().into_vec(), vec![0x00, 0xff]);
+        assert_eq!("0x0".parse::<Bytes>(), Err(BytesError::OddLength));
+        assert_eq!("0xzz".parse::<Bytes>(), Err(BytesError::InvalidHex));
+        assert_eq!("0x+1".parse::<Bytes>(), Err(BytesError::InvalidHex));
+        assert_eq!("0x-1ff".parse::<Bytes>(), Err(BytesError::InvalidHex));
+        assert_eq!("0x 1".parse::<Bytes>(), Err(BytesError::InvalidHex));
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let bytes: Bytes = serde_json::from_str("\"0xDEADbeef\"").unwrap();
+        assert_eq!(serde_json::to_string(&bytes).unwrap(), "\"0xdeadbeef\"");
+    }
+}
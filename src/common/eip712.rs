@@ -0,0 +1,175 @@
+use crate::{
+    common::{address::Address, amount::TokenAmount},
+    utils::keccak::keccak256,
+};
+
+const DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+
+/// [EIP-712](https://eips.ethereum.org/EIPS/eip-712) domain the typed data is
+/// signed for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub verifying_contract: Address,
+}
+
+impl Eip712Domain {
+    pub fn new(name: impl Into<String>, version: impl Into<String>, chain_id: u64, verifying_contract: Address) -> Self {
+        Eip712Domain { name: name.into(), version: version.into(), chain_id, verifying_contract }
+    }
+
+    /// Computes the domain separator.
+    pub fn separator(&self) -> [u8; 32] {
+        hash_words(&[
+            type_hash(DOMAIN_TYPE),
+            encode_string(&self.name),
+            encode_string(&self.version),
+            encode_u64(self.chain_id),
+            encode_address(&self.verifying_contract),
+        ])
+    }
+}
+
+/// Struct which can be hashed and signed according to EIP-712.
+///
+/// Implementors provide the type string and encoded members, hashing is
+/// implemented on top of them.
+pub trait Eip712 {
+    /// Encoded type of the struct, including the referenced types, e.g.
+    /// `Mail(Person from,Person to,string contents)Person(string name,address wallet)`.
+    fn type_string() -> &'static str;
+
+    /// Members of the struct encoded into 32-byte words, in the order of the
+    /// type string.
+    fn encode_data(&self) -> Vec<[u8; 32]>;
+
+    /// Computes `hashStruct` of the value.
+    fn struct_hash(&self) -> [u8; 32] {
+        let mut words = vec![type_hash(Self::type_string())];
+        words.extend(self.encode_data());
+        hash_words(&words)
+    }
+
+    /// Computes the hash to be signed for the value in the given domain.
+    fn signing_hash(&self, domain: &Eip712Domain) -> [u8; 32] {
+        typed_data_hash(&domain.separator(), &self.struct_hash())
+    }
+}
+
+/// Computes `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+pub fn typed_data_hash(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(66);
+    data.extend_from_slice(&[0x19, 0x01]);
+    data.extend_from_slice(domain_separator);
+    data.extend_from_slice(struct_hash);
+    keccak256(data)
+}
+
+/// Computes the type hash of the encoded type.
+pub fn type_hash(type_string: &str) -> [u8; 32] {
+    keccak256(type_string)
+}
+
+/// Encodes `address` member.
+pub fn encode_address(address: &Address) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address.as_bytes());
+    word
+}
+
+/// Encodes `uint256` member.
+pub fn encode_uint(value: &TokenAmount) -> [u8; 32] {
+    value.to_be_bytes()
+}
+
+/// Encodes unsigned integer member which fits into `u64`.
+pub fn encode_u64(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Encodes `bool` member.
+pub fn encode_bool(value: bool) -> [u8; 32] {
+    encode_u64(value as u64)
+}
+
+/// Encodes `string` member as the hash of its contents.
+pub fn encode_string(value: &str) -> [u8; 32] {
+    keccak256(value)
+}
+
+/// Encodes `bytes` member as the hash of its contents.
+pub fn encode_bytes(value: &[u8]) -> [u8; 32] {
+    keccak256(value)
+}
+
+fn hash_words(words: &[[u8; 32]]) -> [u8; 32] {
+    keccak256(words.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example from the EIP-712 specification.
+    struct Person {
+        name: &'static str,
+        wallet: Address,
+    }
+
+    struct Mail {
+        from: Person,
+        to: Person,
+        contents: &'static str,
+    }
+
+    impl Eip712 for Person {
+        fn type_string() -> &'static str {
+            "Person(string name,address wallet)"
+        }
+
+        fn encode_data(&self) -> Vec<[u8; 32]> {
+            vec![encode_string(self.name), encode_address(&self.wallet)]
+        }
+    }
+
+    impl Eip712 for Mail {
+        fn type_string() -> &'static str {
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        }
+
+        fn encode_data(&self) -> Vec<[u8; 32]> {
+            vec![self.from.struct_hash(), self.to.struct_hash(), encode_string(self.contents)]
+        }
+    }
+
+    fn hex(hash: [u8; 32]) -> String {
+        hash.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_eip712_specification_example() {
+        let domain = Eip712Domain::new("Ether Mail", "1", 1, "0xcccccccccccccccccccccccccccccccccccccccc".parse().unwrap());
+        assert_eq!(
+            hex(domain.separator()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+
+        let mail = Mail {
+            from: Person { name: "Cow", wallet: "0xcd2a3d9f938e13cd947ec05abc7fe734df8dd826".parse().unwrap() },
+            to: Person { name: "Bob", wallet: "0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb".parse().unwrap() },
+            contents: "Hello, Bob!",
+        };
+        assert_eq!(
+            hex(mail.struct_hash()),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        assert_eq!(
+            hex(mail.signing_hash(&domain)),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+    }
+}
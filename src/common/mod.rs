@@ -1 +1,6 @@
+pub mod address;
+pub mod amount;
+pub mod bytes;
+pub mod eip712;
+pub mod network;
 pub mod token;
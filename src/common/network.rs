@@ -0,0 +1,323 @@
+use crate::common::address::Address;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr, time::Duration};
+use strum_macros::FromRepr;
+use thiserror::Error;
+
+/// List of all supported Networks/Chains with their IDs.
+///
+/// Besides the chain id every network exposes static metadata like its
+/// native currency, wrapped native token and block explorer. Networks can be
+/// parsed (and deserialized, e.g. from TOML configs) from either chain id or
+/// name:
+///
+/// ```
+/// use one_inch::common::network::SupportedNetworks;
+///
+/// assert_eq!("137".parse::<SupportedNetworks>().unwrap(), SupportedNetworks::Polygon);
+/// assert_eq!("polygon".parse::<SupportedNetworks>().unwrap(), SupportedNetworks::Polygon);
+/// assert_eq!(SupportedNetworks::BSC.native_currency_symbol(), "BNB");
+/// ```
+#[derive(FromRepr, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum SupportedNetworks {
+    Ethereum = 1,
+    Optimism = 10,
+    BSC = 56,
+    Gnosis = 100,
+    Polygon = 137,
+    Fantom = 250,
+    ZkSync = 324,
+    Klaytn = 8217,
+    Base = 8453,
+    Arbitrum = 42161,
+    Avalanche = 43114,
+    Aurora = 1313161554,
+}
+
+/// Enumerates potential errors when parsing `SupportedNetworks`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum NetworkParseError {
+    /// Indicates the value is neither a supported chain id nor a known name.
+    #[error("Unknown network: {0}")]
+    UnknownNetwork(String),
+}
+
+impl SupportedNetworks {
+    /// All the supported networks.
+    pub const ALL: [SupportedNetworks; 12] = [
+        SupportedNetworks::Ethereum,
+        SupportedNetworks::Optimism,
+        SupportedNetworks::BSC,
+        SupportedNetworks::Gnosis,
+        SupportedNetworks::Polygon,
+        SupportedNetworks::Fantom,
+        SupportedNetworks::ZkSync,
+        SupportedNetworks::Klaytn,
+        SupportedNetworks::Base,
+        SupportedNetworks::Arbitrum,
+        SupportedNetworks::Avalanche,
+        SupportedNetworks::Aurora,
+    ];
+
+    /// Returns the chain id of the network.
+    pub fn chain_id(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Returns the network by its chain id.
+    pub fn from_chain_id(chain_id: u32) -> Option<Self> {
+        SupportedNetworks::from_repr(chain_id)
+    }
+
+    /// Returns human readable name of the network.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SupportedNetworks::Ethereum => "Ethereum",
+            SupportedNetworks::Optimism => "Optimism",
+            SupportedNetworks::BSC => "BNB Chain",
+            SupportedNetworks::Gnosis => "Gnosis",
+            SupportedNetworks::Polygon => "Polygon",
+            SupportedNetworks::Fantom => "Fantom",
+            SupportedNetworks::ZkSync => "zkSync Era",
+            SupportedNetworks::Klaytn => "Klaytn",
+            SupportedNetworks::Base => "Base",
+            SupportedNetworks::Arbitrum => "Arbitrum One",
+            SupportedNetworks::Avalanche => "Avalanche",
+            SupportedNetworks::Aurora => "Aurora",
+        }
+    }
+
+    /// Returns symbol of the native currency of the network.
+    pub fn native_currency_symbol(&self) -> &'static str {
+        match self {
+            SupportedNetworks::Ethereum
+            | SupportedNetworks::Optimism
+            | SupportedNetworks::ZkSync
+            | SupportedNetworks::Base
+            | SupportedNetworks::Arbitrum
+            | SupportedNetworks::Aurora => "ETH",
+            SupportedNetworks::BSC => "BNB",
+            SupportedNetworks::Gnosis => "xDAI",
+            SupportedNetworks::Polygon => "MATIC",
+            SupportedNetworks::Fantom => "FTM",
+            SupportedNetworks::Klaytn => "KLAY",
+            SupportedNetworks::Avalanche => "AVAX",
+        }
+    }
+
+    /// Returns decimals of the native currency of the network.
+    pub fn native_currency_decimals(&self) -> u8 {
+        18
+    }
+
+    /// Returns address of the wrapped native token (WETH, WBNB, ...) of the
+    /// network.
+    pub fn wrapped_native_token(&self) -> Address {
+        let address = match self {
+            SupportedNetworks::Ethereum => "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+            SupportedNetworks::Optimism | SupportedNetworks::Base => "0x4200000000000000000000000000000000000006",
+            SupportedNetworks::BSC => "0xbb4cdb9cbd36b01bd1cbaebf2de08d9173bc095c",
+            SupportedNetworks::Gnosis => "0xe91d153e0b41518a2ce8dd3d7944fa863463a97d",
+            SupportedNetworks::Polygon => "0x0d500b1d8e8ef31e21c99d1db9a6444d3adf1270",
+            SupportedNetworks::Fantom => "0x21be370d5312f44cb42ce377bc9b8a0cef1a4c83",
+            SupportedNetworks::ZkSync => "0x5aea5775959fbc2557cc8789bc1bf90a239d9a91",
+            SupportedNetworks::Klaytn => "0xe4f05a66ec68b54a58b17c22107b02e0232cc817",
+            SupportedNetworks::Arbitrum => "0x82af49447d8a07e3bd95bd0d56f35241523fbab1",
+            SupportedNetworks::Avalanche => "0xb31f66aa3c1e785363f0875a1b74e27b85fd66c7",
+            SupportedNetworks::Aurora => "0xc9bdeed33cd01541e1eed10f90519d2c06fe3feb",
+        };
+
+        address.parse().expect("Wrapped native token addresses are valid")
+    }
+
+    /// Returns base url of the main block explorer of the network, without a
+    /// trailing slash.
+    pub fn explorer_url(&self) -> &'static str {
+        match self {
+            SupportedNetworks::Ethereum => "https://etherscan.io",
+            SupportedNetworks::Optimism => "https://optimistic.etherscan.io",
+            SupportedNetworks::BSC => "https://bscscan.com",
+            SupportedNetworks::Gnosis => "https://gnosisscan.io",
+            SupportedNetworks::Polygon => "https://polygonscan.com",
+            SupportedNetworks::Fantom => "https://ftmscan.com",
+            SupportedNetworks::ZkSync => "https://explorer.zksync.io",
+            SupportedNetworks::Klaytn => "https://klaytnscope.com",
+            SupportedNetworks::Base => "https://basescan.org",
+            SupportedNetworks::Arbitrum => "https://arbiscan.io",
+            SupportedNetworks::Avalanche => "https://snowtrace.io",
+            SupportedNetworks::Aurora => "https://explorer.aurora.dev",
+        }
+    }
+
+    /// Returns explorer link to the transaction with specified hash.
+    pub fn explorer_tx_url(&self, tx_hash: &str) -> String {
+        format!("{}/tx/{}", self.explorer_url(), tx_hash)
+    }
+
+    /// Returns explorer link to the specified account or contract.
+    pub fn explorer_address_url(&self, address: &Address) -> String {
+        format!("{}/address/{}", self.explorer_url(), address)
+    }
+
+    /// Returns explorer link to the specified token.
+    pub fn explorer_token_url(&self, token: &Address) -> String {
+        format!("{}/token/{}", self.explorer_url(), token)
+    }
+
+    /// Returns whether the network supports EIP-1559 transactions.
+    ///
+    /// Based on the hard forks activating type 2 transactions on every
+    /// network, e.g. London on Ethereum, Apricot Phase 3 on Avalanche and Hertz
+    /// (BEP-226, April 2023) on BSC. Aurora isn't included, transactions there
+    /// are built as legacy ones.
+    pub fn supports_eip1559(&self) -> bool {
+        !matches!(self, SupportedNetworks::Aurora)
+    }
+
+    /// Returns approximate average time between blocks of the network.
+    pub fn average_block_time(&self) -> Duration {
+        match self {
+            SupportedNetworks::Ethereum => Duration::from_secs(12),
+            SupportedNetworks::Optimism | SupportedNetworks::Base | SupportedNetworks::Polygon => Duration::from_secs(2),
+            SupportedNetworks::BSC => Duration::from_secs(3),
+            SupportedNetworks::Gnosis => Duration::from_secs(5),
+            SupportedNetworks::Fantom | SupportedNetworks::ZkSync | SupportedNetworks::Klaytn | SupportedNetworks::Aurora => {
+                Duration::from_secs(1)
+            }
+            SupportedNetworks::Arbitrum => Duration::from_millis(250),
+            SupportedNetworks::Avalanche => Duration::from_secs(2),
+        }
+    }
+}
+
+impl fmt::Display for SupportedNetworks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u32)
+    }
+}
+
+impl FromStr for SupportedNetworks {
+    type Err = NetworkParseError;
+
+    /// Parses the network from its chain id or from its name. Names are case
+    /// insensitive and common aliases (`eth`, `bnb`, `matic`, `arb`, ...) are
+    /// accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || NetworkParseError::UnknownNetwork(s.to_string());
+        let value = s.trim();
+
+        if let Ok(chain_id) = value.parse::<u32>() {
+            return SupportedNetworks::from_chain_id(chain_id).ok_or_else(unknown);
+        }
+
+        let network = match value.to_lowercase().replace(['-', '_'], " ").as_str() {
+            "ethereum" | "eth" | "mainnet" => SupportedNetworks::Ethereum,
+            "optimism" | "op" => SupportedNetworks::Optimism,
+            "bsc" | "bnb" | "bnb chain" | "binance" | "binance smart chain" => SupportedNetworks::BSC,
+            "gnosis" | "xdai" => SupportedNetworks::Gnosis,
+            "polygon" | "matic" => SupportedNetworks::Polygon,
+            "fantom" | "ftm" => SupportedNetworks::Fantom,
+            "zksync" | "zksync era" => SupportedNetworks::ZkSync,
+            "klaytn" | "klay" => SupportedNetworks::Klaytn,
+            "base" => SupportedNetworks::Base,
+            "arbitrum" | "arbitrum one" | "arb" => SupportedNetworks::Arbitrum,
+            "avalanche" | "avax" => SupportedNetworks::Avalanche,
+            "aurora" => SupportedNetworks::Aurora,
+            _ => return Err(unknown()),
+        };
+
+        Ok(network)
+    }
+}
+
+impl Serialize for SupportedNetworks {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.chain_id())
+    }
+}
+
+impl<'de> Deserialize<'de> for SupportedNetworks {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NetworkVisitor;
+
+        impl<'de> de::Visitor<'de> for NetworkVisitor {
+            type Value = SupportedNetworks;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a supported chain id or network name")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                u32::try_from(value)
+                    .ok()
+                    .and_then(SupportedNetworks::from_chain_id)
+                    .ok_or_else(|| E::custom(NetworkParseError::UnknownNetwork(value.to_string())))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map_err(|_| E::custom(NetworkParseError::UnknownNetwork(value.to_string())))
+                    .and_then(|value| self.visit_u64(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(NetworkVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_network() {
+        assert_eq!("56".parse::<SupportedNetworks>().unwrap(), SupportedNetworks::BSC);
+        assert_eq!("BSC".parse::<SupportedNetworks>().unwrap(), SupportedNetworks::BSC);
+        assert_eq!("Arbitrum-One".parse::<SupportedNetworks>().unwrap(), SupportedNetworks::Arbitrum);
+        assert_eq!("zksync era".parse::<SupportedNetworks>().unwrap(), SupportedNetworks::ZkSync);
+        assert!("57".parse::<SupportedNetworks>().is_err());
+        assert!("solana".parse::<SupportedNetworks>().is_err());
+    }
+
+    #[test]
+    fn test_serde_network() {
+        #[derive(Deserialize)]
+        struct Config {
+            networks: Vec<SupportedNetworks>,
+        }
+
+        let config: Config = serde_json::from_str(r#"{"networks": [1, "polygon", "8453"]}"#).unwrap();
+        assert_eq!(
+            config.networks,
+            vec![SupportedNetworks::Ethereum, SupportedNetworks::Polygon, SupportedNetworks::Base]
+        );
+        assert_eq!(serde_json::to_string(&SupportedNetworks::Aurora).unwrap(), "1313161554");
+        assert!(serde_json::from_str::<SupportedNetworks>("-1").is_err());
+    }
+
+    #[test]
+    fn test_metadata() {
+        for network in SupportedNetworks::ALL {
+            // Display must stay the chain id, as it's used to build urls
+            assert_eq!(network.to_string().parse::<SupportedNetworks>().unwrap(), network);
+            assert_eq!(network.name().parse::<SupportedNetworks>().unwrap(), network);
+            assert!(!network.wrapped_native_token().is_native_token());
+        }
+
+        assert_eq!(
+            SupportedNetworks::Ethereum.explorer_tx_url("0xabc"),
+            "https://etherscan.io/tx/0xabc".to_string()
+        );
+        assert_eq!(
+            SupportedNetworks::Ethereum.wrapped_native_token().to_string(),
+            "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"
+        );
+        assert!(SupportedNetworks::BSC.supports_eip1559());
+        assert!(!SupportedNetworks::Aurora.supports_eip1559());
+    }
+}
@@ -1,16 +1,25 @@
+use crate::common::{
+    address::Address,
+    amount::{TokenAmount, TokenAmountError},
+};
 use serde::Deserialize;
 
 /// Struct defines TokenInfo object.
-/// Contains basic information about specific token
-#[derive(Debug, Deserialize)]
+/// Contains basic information about specific token, as both the swap API and
+/// the token API return it. Fields only some of the endpoints return are
+/// optional.
+#[derive(Debug, Clone, Deserialize)]
 pub struct TokenInfo {
-    pub address: String,
+    pub address: Address,
     pub symbol: String,
     pub name: String,
     pub decimals: u8,
 
+    #[serde(rename = "chainId")]
+    pub chain_id: Option<u32>,
+
     #[serde(rename = "logoURI")]
-    pub logo_uri: String,
+    pub logo_uri: Option<String>,
 
     #[serde(rename = "domainVersion")]
     pub domain_version: Option<String>,
@@ -21,5 +30,27 @@ pub struct TokenInfo {
     #[serde(rename = "isFoT")]
     pub is_fot: Option<bool>,
 
+    #[serde(default)]
     pub tags: Vec<String>,
+
+    /// Token lists the token is included in.
+    #[serde(default)]
+    pub providers: Vec<String>,
+
+    /// Rating of the token by 1inch, custom tokens have the lowest one.
+    pub rating: Option<u32>,
+}
+
+impl TokenInfo {
+    /// Converts human readable value like `"1.5"` into smallest units of the
+    /// token using its decimals.
+    pub fn parse_amount(&self, value: &str) -> Result<TokenAmount, TokenAmountError> {
+        TokenAmount::from_units(value, self.decimals)
+    }
+
+    /// Converts amount in smallest units of the token into human readable
+    /// value using its decimals.
+    pub fn format_amount(&self, amount: &TokenAmount) -> String {
+        amount.to_units(self.decimals)
+    }
 }
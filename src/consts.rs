@@ -4,8 +4,9 @@
 pub const BASIC_URL: &str = "https://api.1inch.dev";
 
 pub const SWAP_API_VERSION: &str = "v5.2";
-pub const ORDERBOOK_API_VERSION: &str = "v3.0";
-pub const FUSION_API_VERSION: &str = "v1.0";
+pub const SWAP_API_V6_VERSION: &str = "v6.0";
+pub const ORDERBOOK_API_VERSION: &str = "v4.0";
+pub const FUSION_API_VERSION: &str = "v2.0";
 pub const HISTORY_API_VERSION: &str = "v2.0";
 pub const TRACES_API_VERSION: &str = "v1.0";
 pub const PORTFOLIO_API_VERSION: &str = "v3";
@@ -14,3 +15,8 @@ pub const GAS_PRICE_API_VERSION: &str = "v1.4";
 pub const SPOT_PRICE_API_VERSION: &str = "v1.1";
 pub const TOKENS_API_VERSION: &str = "v1.2";
 pub const NFT_API_VERSION: &str = "v1";
+
+// Limit Order Protocol v4 (1inch Aggregation Router v6) contract, which is
+// the same on all the chains except zkSync.
+pub const LIMIT_ORDER_PROTOCOL_ADDRESS: &str = "0x111111125421ca6dc452d289314280a0f8842a65";
+pub const LIMIT_ORDER_PROTOCOL_ZKSYNC_ADDRESS: &str = "0x6fd4383cb451173d5f9304f041c7bcbf27d561ff";
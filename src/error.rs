@@ -0,0 +1,114 @@
+use serde::Deserialize;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Represents every error that can occur while performing a request to the
+/// 1inch API.
+///
+/// All the endpoints of [`OneInchClient`](crate::client::OneInchClient) return
+/// this type, so the kind of failure can be found out with a simple `match`
+/// instead of downcasting.
+#[derive(Error, Debug)]
+pub enum OneInchError {
+    /// Error related to network requests.
+    ///
+    /// Used for handling issues with network requests, such as server
+    /// unavailability, network connectivity problems, timeouts, etc.
+    #[error("Network error: {0}")]
+    Network(reqwest::Error),
+
+    /// The server responded with a non-successful status code.
+    ///
+    /// `api_error` contains the parsed error body if the server returned one
+    /// in the usual 1inch format, `body` always contains the raw response
+    /// body.
+    #[error("Server responded with status {status}: {}", api_error.as_ref().map(|e| e.description.as_str()).unwrap_or(body))]
+    Http { status: u16, api_error: Option<Box<ApiErrorBody>>, body: String },
+
+    /// The server responded with `429 Too Many Requests`.
+    ///
+    /// `retry_after` contains the delay requested by the server in the
+    /// `Retry-After` header, if any.
+    #[error("Rate limited by server{}", retry_after.map(|d| format!(", retry after {}s", d.as_secs())).unwrap_or_default())]
+    RateLimited { retry_after: Option<Duration> },
+
+    /// Error while parsing JSON.
+    ///
+    /// Occurs when the server's response cannot be correctly deserialized from
+    /// JSON. This could happen if the response format is different than
+    /// expected. `body` contains the raw response body.
+    #[error("JSON parsing error: {source}")]
+    Deserialization { source: serde_json::Error, body: String },
+
+    /// Error raised by a custom [`Transport`](crate::transport::Transport),
+    /// e.g. when no mock matches the request.
+    #[error("Transport error: {0}")]
+    Transport(String),
+
+    /// Error caused by invalid request parameters detected before sending the
+    /// request.
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+}
+
+impl OneInchError {
+    /// Returns the http status code of the response that caused the error, if
+    /// the server responded at all.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            OneInchError::Http { status, .. } => Some(*status),
+            OneInchError::RateLimited { .. } => Some(429),
+            OneInchError::Network(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Returns the parsed error body returned by the API, if any.
+    pub fn api_error(&self) -> Option<&ApiErrorBody> {
+        match self {
+            OneInchError::Http { api_error, .. } => api_error.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Represents an error response from the 1inch API.
+///
+/// This structure is used to deserialize the JSON error response returned by
+/// the API. It contains details about the error that occurred during a
+/// request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiErrorBody {
+    /// A brief description of the error.
+    pub error: String,
+
+    /// A more detailed description of the error.
+    pub description: String,
+
+    /// The HTTP status code associated with the error.
+    #[serde(rename = "statusCode")]
+    pub status_code: u16,
+
+    /// A unique identifier for the request, useful for debugging.
+    #[serde(rename = "requestId")]
+    pub request_id: String,
+
+    /// Additional metadata related to the error, if any.
+    #[serde(default)]
+    pub meta: Vec<HttpExceptionMeta>,
+}
+
+/// Represents additional metadata in the API error response.
+///
+/// Each item in the `meta` field of `ApiErrorBody` will be deserialized into
+/// this structure. It provides more context about the error, such as the
+/// affected parameters or values.
+#[derive(Deserialize, Debug, Clone)]
+pub struct HttpExceptionMeta {
+    /// The type of metadata.
+    #[serde(rename = "type")]
+    pub type_field: String,
+
+    /// The value associated with this metadata.
+    pub value: String,
+}
@@ -0,0 +1,313 @@
+use crate::{
+    common::{address::Address, bytes::Bytes},
+    fusion::AuctionPoint,
+    orderbook::{Extension, Interaction},
+};
+use thiserror::Error;
+
+// Whitelist length is stored in the 5 high bits of the post-interaction mask.
+const MAX_WHITELIST_LEN: usize = 31;
+
+/// Enumerates potential errors when encoding `FusionExtension`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum FusionExtensionError {
+    /// Indicates a value doesn't fit into its packed width.
+    #[error("{field} must fit into {bits} bits")]
+    ValueTooWide { field: &'static str, bits: usize },
+
+    /// Indicates the whitelist has more resolvers than the mask can hold.
+    #[error("Whitelist can't contain more than {MAX_WHITELIST_LEN} resolvers, got {0}")]
+    TooManyResolvers(usize),
+}
+
+/// Dutch auction of the Fusion order, read by the settlement extension to
+/// compute making and taking amounts at the moment of the fill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuctionDetails {
+    /// Unix timestamp the auction starts at.
+    pub start_time: u32,
+
+    /// Duration of the auction in seconds.
+    pub duration: u32,
+
+    /// Rate bump the auction starts with, in 1/100000 units.
+    pub initial_rate_bump: u32,
+    pub points: Vec<AuctionPoint>,
+
+    /// Gas estimates the rate bump is adjusted with when the network is
+    /// cheaper than expected. Zero disables the adjustment.
+    pub gas_bump_estimate: u32,
+    pub gas_price_estimate: u32,
+}
+
+impl AuctionDetails {
+    /// Encodes the auction as packed
+    /// `gasBumpEstimate (uint24) ‖ gasPriceEstimate (uint32) ‖ startTime (uint32) ‖ duration (uint24) ‖ initialRateBump (uint24)`
+    /// followed by `coefficient (uint24) ‖ delay (uint16)` of every point.
+    ///
+    /// Fails if any value doesn't fit into its packed width.
+    pub fn encode(&self) -> Result<Bytes, FusionExtensionError> {
+        let mut encoded = Vec::with_capacity(17 + self.points.len() * 5);
+
+        encoded.extend(uint_bytes(self.gas_bump_estimate.into(), 3, "gas_bump_estimate")?);
+        encoded.extend(uint_bytes(self.gas_price_estimate.into(), 4, "gas_price_estimate")?);
+        encoded.extend(uint_bytes(self.start_time.into(), 4, "start_time")?);
+        encoded.extend(uint_bytes(self.duration.into(), 3, "duration")?);
+        encoded.extend(uint_bytes(self.initial_rate_bump.into(), 3, "initial_rate_bump")?);
+
+        for point in &self.points {
+            encoded.extend(uint_bytes(point.coefficient.into(), 3, "point coefficient")?);
+            encoded.extend(uint_bytes(point.delay.into(), 2, "point delay")?);
+        }
+
+        Ok(encoded.into())
+    }
+}
+
+/// Resolver allowed to fill the order starting from `allow_from`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WhitelistEntry {
+    pub resolver: Address,
+
+    /// Unix timestamp the resolver can fill the order from. Timestamps before
+    /// the resolving start time, zero included, allow filling right away.
+    pub allow_from: u32,
+}
+
+/// Fee paid by the taker to the integrator, `ratio` is in 1/100000 units of
+/// the taking amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntegratorFee {
+    pub ratio: u16,
+    pub receiver: Address,
+}
+
+/// Data the settlement extension gets in its post-interaction: resolvers
+/// whitelist, fees and the receiver of the taker asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettlementPostInteractionData {
+    /// Unix timestamp resolvers can start filling the order at.
+    pub resolving_start_time: u32,
+    pub whitelist: Vec<WhitelistEntry>,
+    pub bank_fee: u32,
+    pub integrator_fee: Option<IntegratorFee>,
+    pub custom_receiver: Option<Address>,
+}
+
+impl SettlementPostInteractionData {
+    /// Encodes the data as a bit mask byte followed by the fields the mask
+    /// enables, the resolving start time and the whitelist.
+    ///
+    /// Bit 0 of the mask marks the bank fee, bit 1 the integrator fee, bit 2
+    /// the custom receiver and bits 3-7 hold the whitelist length. Only the
+    /// low 10 bytes of the resolver addresses are stored, ordered by the time
+    /// they are allowed from, each with the delay after the previous one.
+    ///
+    /// Fails if the whitelist has more than 31 resolvers or if a delay doesn't
+    /// fit into 16 bits.
+    pub fn encode(&self) -> Result<Bytes, FusionExtensionError> {
+        if self.whitelist.len() > MAX_WHITELIST_LEN {
+            return Err(FusionExtensionError::TooManyResolvers(self.whitelist.len()));
+        }
+
+        let mut mask = (self.whitelist.len() as u8) << 3;
+        let mut encoded = vec![];
+
+        if self.bank_fee != 0 {
+            mask |= 0b001;
+            encoded.extend(uint_bytes(self.bank_fee.into(), 4, "bank_fee")?);
+        }
+
+        if let Some(fee) = &self.integrator_fee {
+            mask |= 0b010;
+            encoded.extend(uint_bytes(fee.ratio.into(), 2, "integrator fee ratio")?);
+            encoded.extend_from_slice(fee.receiver.as_bytes());
+        }
+
+        if let Some(receiver) = &self.custom_receiver {
+            mask |= 0b100;
+            encoded.extend_from_slice(receiver.as_bytes());
+        }
+
+        encoded.extend(uint_bytes(self.resolving_start_time.into(), 4, "resolving_start_time")?);
+
+        let mut whitelist = self.whitelist.clone();
+        whitelist.sort_by_key(|entry| entry.allow_from);
+
+        // Delays are cumulative, each one counts from the previous resolver.
+        let mut active_from = self.resolving_start_time;
+        for entry in &whitelist {
+            let allow_from = entry.allow_from.max(self.resolving_start_time);
+            encoded.extend_from_slice(&entry.resolver.as_bytes()[10..]);
+            encoded.extend(uint_bytes((allow_from - active_from).into(), 2, "whitelist delay")?);
+            active_from = allow_from;
+        }
+
+        let mut data = vec![mask];
+        data.extend(encoded);
+        Ok(data.into())
+    }
+}
+
+/// Extension of the Fusion order, pointing the amount getters and the
+/// post-interaction of the order to the settlement extension contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FusionExtension {
+    /// Settlement extension contract, `settlementAddress` of the quote.
+    pub settlement: Address,
+    pub auction_details: AuctionDetails,
+    pub post_interaction_data: SettlementPostInteractionData,
+
+    /// Permit of the maker asset, called by the protocol before the fill.
+    pub maker_permit: Option<Interaction>,
+}
+
+impl FusionExtension {
+    /// Builds the order [`Extension`], failing if the auction or the
+    /// post-interaction data can't be encoded.
+    pub fn build(&self) -> Result<Extension, FusionExtensionError> {
+        let amount_data = Interaction::new(self.settlement, self.auction_details.encode()?).encode();
+
+        Ok(Extension {
+            making_amount_data: amount_data.clone(),
+            taking_amount_data: amount_data,
+            maker_permit: self.maker_permit.as_ref().map(Interaction::encode).unwrap_or_default(),
+            post_interaction: Interaction::new(self.settlement, self.post_interaction_data.encode()?).encode(),
+            ..Default::default()
+        })
+    }
+}
+
+// Big-endian bytes of the `size` bytes wide unsigned integer, failing if the
+// value doesn't fit.
+fn uint_bytes(value: u64, size: usize, field: &'static str) -> Result<Vec<u8>, FusionExtensionError> {
+    if value >> (size * 8) != 0 {
+        return Err(FusionExtensionError::ValueTooWide { field, bits: size * 8 });
+    }
+    Ok(value.to_be_bytes()[8 - size..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auction_details_encoding() {
+        let details = AuctionDetails {
+            start_time: 0x65_4f_0c_40,
+            duration: 180,
+            initial_rate_bump: 50000,
+            points: vec![AuctionPoint { delay: 60, coefficient: 25000 }, AuctionPoint { delay: 120, coefficient: 0 }],
+            gas_bump_estimate: 10,
+            gas_price_estimate: 1000,
+        };
+
+        // gas bump | gas price | start time | duration | rate bump | points
+        let expected: Bytes = "0x00000a000003e8654f0c400000b400c3500061a8003c0000000078".parse().unwrap();
+        assert_eq!(details.encode().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_auction_details_overflow() {
+        let details = AuctionDetails {
+            start_time: 0,
+            duration: 1 << 24,
+            initial_rate_bump: 0,
+            points: vec![],
+            gas_bump_estimate: 0,
+            gas_price_estimate: 0,
+        };
+        assert_eq!(details.encode(), Err(FusionExtensionError::ValueTooWide { field: "duration", bits: 24 }));
+
+        let details = AuctionDetails { duration: 180, points: vec![AuctionPoint { delay: 1 << 16, coefficient: 0 }], ..details };
+        assert_eq!(
+            details.encode(),
+            Err(FusionExtensionError::ValueTooWide { field: "point delay", bits: 16 })
+        );
+    }
+
+    #[test]
+    fn test_post_interaction_data_encoding() {
+        let start = 0x65_4f_0c_40;
+        let data = SettlementPostInteractionData {
+            resolving_start_time: start,
+            whitelist: vec![
+                WhitelistEntry { resolver: Address::from_bytes([0xbb; 20]), allow_from: start + 12 },
+                WhitelistEntry { resolver: Address::from_bytes([0xcc; 20]), allow_from: start + 30 },
+                WhitelistEntry { resolver: Address::from_bytes([0xaa; 20]), allow_from: 0 },
+            ],
+            bank_fee: 0,
+            integrator_fee: Some(IntegratorFee { ratio: 100, receiver: Address::from_bytes([0x11; 20]) }),
+            custom_receiver: None,
+        };
+
+        let expected: Bytes = format!(
+            "0x{mask}{ratio}{receiver}{start}{first}0000{second}000c{third}0012",
+            mask = "1a", // whitelist length 3 << 3 | integrator fee
+            ratio = "0064",
+            receiver = "11".repeat(20),
+            start = "654f0c40",
+            first = "aa".repeat(10),
+            second = "bb".repeat(10),
+            third = "cc".repeat(10),
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(data.encode().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_post_interaction_data_limits() {
+        let entry = WhitelistEntry { resolver: Address::from_bytes([0xaa; 20]), allow_from: 0 };
+        let mut data = SettlementPostInteractionData {
+            resolving_start_time: 1,
+            whitelist: vec![entry; 31],
+            bank_fee: 0,
+            integrator_fee: None,
+            custom_receiver: None,
+        };
+        assert_eq!(data.encode().unwrap()[0], 31 << 3);
+
+        data.whitelist.push(entry);
+        assert_eq!(data.encode(), Err(FusionExtensionError::TooManyResolvers(32)));
+
+        data.whitelist = vec![WhitelistEntry { allow_from: 1 + (1 << 16), ..entry }];
+        assert_eq!(
+            data.encode(),
+            Err(FusionExtensionError::ValueTooWide { field: "whitelist delay", bits: 16 })
+        );
+    }
+
+    #[test]
+    fn test_fusion_extension() {
+        let settlement = Address::from_bytes([0x22; 20]);
+        let extension = FusionExtension {
+            settlement,
+            auction_details: AuctionDetails {
+                start_time: 1,
+                duration: 2,
+                initial_rate_bump: 3,
+                points: vec![],
+                gas_bump_estimate: 0,
+                gas_price_estimate: 0,
+            },
+            post_interaction_data: SettlementPostInteractionData {
+                resolving_start_time: 1,
+                whitelist: vec![],
+                bank_fee: 0,
+                integrator_fee: None,
+                custom_receiver: None,
+            },
+            maker_permit: None,
+        }
+        .build()
+        .unwrap();
+
+        let amount_data = Interaction::decode(&extension.making_amount_data).unwrap();
+        assert_eq!(amount_data.target, settlement);
+        assert_eq!(amount_data.data.len(), 17);
+        assert_eq!(extension.making_amount_data, extension.taking_amount_data);
+        assert_eq!(Interaction::decode(&extension.post_interaction).unwrap().data.to_string(), "0x0000000001");
+        assert!(extension.maker_permit.is_empty());
+    }
+}
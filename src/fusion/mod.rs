@@ -0,0 +1,10 @@
+mod extension;
+mod order;
+mod orders;
+mod quoter;
+mod relayer;
+mod types;
+
+pub use extension::*;
+pub use order::*;
+pub use types::*;
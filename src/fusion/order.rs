@@ -0,0 +1,391 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    common::address::Address,
+    fusion::{
+        AuctionDetails, FusionExtension, FusionExtensionError, FusionQuoteDetails, FusionQuoteResponse, IntegratorFee, Preset,
+        PresetKind, SettlementPostInteractionData, SignedFusionOrder, WhitelistEntry,
+    },
+    orderbook::{Interaction, LimitOrder, LimitOrderBuilder, LimitOrderBuilderError, MakerTraits, MakerTraitsError},
+    signer::{Signer, SignerError},
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+// Nonce of the orders which can't be filled more than once is 40 bits wide.
+const NONCE_MASK: u64 = (1 << 40) - 1;
+
+/// Fusion order built from the quote, ready to be signed.
+#[derive(Debug, Clone)]
+pub struct FusionOrder {
+    pub order: LimitOrder,
+    pub extension: FusionExtension,
+    pub quote_id: String,
+}
+
+impl FusionOrder {
+    /// Signs the order for the network, producing the payload accepted by
+    /// [`submit_fusion_order`](crate::client::OneInchClient::submit_fusion_order).
+    pub fn sign<S: Signer>(&self, signer: &S, network: SupportedNetworks) -> Result<SignedFusionOrder, SignerError> {
+        let signature = signer.sign_limit_order(&self.order, network)?;
+        Ok(SignedFusionOrder::new(&self.order, &signature, self.quote_id.clone()))
+    }
+}
+
+/// Enumerates potential errors when building `FusionOrder`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum FusionOrderBuilderError {
+    /// Indicates a required field is missing its value.
+    #[error("Missing {0}")]
+    MissingField(&'static str),
+
+    /// Indicates the quote doesn't contain the chosen preset.
+    #[error("Quote has no {0} preset")]
+    MissingPreset(PresetKind),
+
+    /// Indicates a preset value doesn't fit into the auction encoding.
+    #[error("Invalid preset {0}")]
+    InvalidPreset(&'static str),
+
+    /// Indicates the extension can't be encoded, e.g. the quote whitelists
+    /// too many resolvers.
+    #[error(transparent)]
+    Extension(#[from] FusionExtensionError),
+
+    /// Indicates the expiration or the nonce doesn't fit into the maker
+    /// traits.
+    #[error(transparent)]
+    MakerTraits(#[from] MakerTraitsError),
+
+    /// Indicates the underlying limit order can't be built.
+    #[error(transparent)]
+    LimitOrder(#[from] LimitOrderBuilderError),
+}
+
+/// Builder to create instance of [`FusionOrder`](crate::fusion::FusionOrder)
+/// from the Fusion quote and the chosen preset.
+///
+/// The auction of the preset, whitelisted resolvers of the quote and the fees
+/// are encoded into the extension handled by the settlement contract of the
+/// quote. The auction starts `start_auction_by` seconds of the preset from
+/// now unless the start time is set explicitly, and the order expires when
+/// the auction ends. Resolvers can fill the order from the start of the
+/// auction, while the `exclusive_resolver` of the preset, if any, can fill it
+/// `start_auction_by` seconds earlier.
+///
+/// ```no_run
+/// # async fn example(client: one_inch::client::OneInchClient, details: one_inch::fusion::FusionQuoteDetails) {
+/// use one_inch::{client::SupportedNetworks, fusion::{FusionOrderBuilder, PresetKind}, signer::LocalSigner};
+///
+/// let signer: LocalSigner = std::env::var("PRIVATE_KEY").unwrap().parse().unwrap();
+/// let quote = client.get_fusion_quote(details.clone()).await.unwrap();
+///
+/// let order = FusionOrderBuilder::new(&quote, PresetKind::Fast).details(&details).build().unwrap();
+/// let signed = order.sign(&signer, SupportedNetworks::Ethereum).unwrap();
+/// client.submit_fusion_order(signed).await.unwrap();
+/// # }
+/// ```
+pub struct FusionOrderBuilder<'a> {
+    quote: &'a FusionQuoteResponse,
+    preset: PresetKind,
+    maker: Option<Address>,
+    maker_asset: Option<Address>,
+    taker_asset: Option<Address>,
+    receiver: Option<Address>,
+    auction_start_time: Option<u32>,
+    nonce: Option<u64>,
+    integrator_fee: Option<IntegratorFee>,
+    maker_permit: Option<Interaction>,
+}
+
+impl<'a> FusionOrderBuilder<'a> {
+    pub fn new(quote: &'a FusionQuoteResponse, preset: PresetKind) -> Self {
+        FusionOrderBuilder {
+            quote,
+            preset,
+            maker: None,
+            maker_asset: None,
+            taker_asset: None,
+            receiver: None,
+            auction_start_time: None,
+            nonce: None,
+            integrator_fee: None,
+            maker_permit: None,
+        }
+    }
+
+    builder_setter!(maker, Address);
+    builder_setter!(maker_asset, Address);
+    builder_setter!(taker_asset, Address);
+    builder_setter!(receiver, Address);
+    builder_setter!(auction_start_time, u32);
+    builder_setter!(nonce, u64);
+    builder_setter!(integrator_fee, IntegratorFee);
+    builder_setter!(maker_permit, Interaction);
+
+    /// Takes the maker and the assets from the details the quote was
+    /// requested with.
+    pub fn details(self, details: &FusionQuoteDetails) -> Self {
+        self.maker(details.wallet_address).maker_asset(details.from_token_address).taker_asset(details.to_token_address)
+    }
+
+    /// Attempts to construct a [`FusionOrder`](crate::fusion::FusionOrder)
+    /// from the builder, returning errors if required fields are missing or if
+    /// the preset can't be encoded.
+    pub fn build(self) -> Result<FusionOrder, FusionOrderBuilderError> {
+        let preset = self.quote.presets.get(self.preset).ok_or(FusionOrderBuilderError::MissingPreset(self.preset))?;
+        let quote_id = self.quote.quote_id.clone().ok_or(FusionOrderBuilderError::MissingField("quote_id"))?;
+        let maker = self.maker.ok_or(FusionOrderBuilderError::MissingField("maker"))?;
+
+        let start_time = match self.auction_start_time {
+            Some(start_time) => start_time,
+            None => now().saturating_add(preset.start_auction_by),
+        };
+
+        // Without an exclusive resolver every whitelisted one can fill the order
+        // as soon as resolving starts.
+        let whitelist = self
+            .quote
+            .whitelist
+            .iter()
+            .map(|&resolver| WhitelistEntry {
+                resolver,
+                allow_from: match preset.exclusive_resolver {
+                    Some(exclusive) if exclusive != resolver => start_time,
+                    _ => 0,
+                },
+            })
+            .collect();
+
+        let extension = FusionExtension {
+            settlement: self.quote.settlement_address,
+            auction_details: auction_details(preset, start_time)?,
+            post_interaction_data: SettlementPostInteractionData {
+                resolving_start_time: start_time.saturating_sub(preset.start_auction_by),
+                whitelist,
+                bank_fee: 0,
+                integrator_fee: self.integrator_fee,
+                custom_receiver: self.receiver.filter(|_| self.integrator_fee.is_some()),
+            },
+            maker_permit: self.maker_permit,
+        };
+
+        // Fees are charged by the settlement contract, so the taker asset goes
+        // through it before reaching the receiver.
+        let receiver = match self.integrator_fee {
+            Some(_) => self.quote.settlement_address,
+            None => self.receiver.unwrap_or(Address::ZERO),
+        };
+
+        let mut maker_traits = MakerTraits::default()
+            .with_expiration(u64::from(start_time) + u64::from(preset.auction_duration))?
+            .with_partial_fills(preset.allow_partial_fills)
+            .with_multiple_fills(preset.allow_multiple_fills);
+
+        // Orders filled once are invalidated by nonce, which must be unique.
+        let nonce = match self.nonce {
+            Some(nonce) => Some(nonce),
+            None if !preset.allow_multiple_fills => Some(rand::random::<u64>() & NONCE_MASK),
+            None => None,
+        };
+        if let Some(nonce) = nonce {
+            maker_traits = maker_traits.with_nonce(nonce)?;
+        }
+
+        let order = LimitOrderBuilder::new()
+            .maker(maker)
+            .receiver(receiver)
+            .maker_asset(self.maker_asset.ok_or(FusionOrderBuilderError::MissingField("maker_asset"))?)
+            .taker_asset(self.taker_asset.ok_or(FusionOrderBuilderError::MissingField("taker_asset"))?)
+            .making_amount(self.quote.from_token_amount.clone())
+            .taking_amount(preset.auction_end_amount.clone())
+            .maker_traits(maker_traits)
+            .extension(extension.build()?)
+            .build()?;
+
+        Ok(FusionOrder { order, extension, quote_id })
+    }
+}
+
+fn auction_details(preset: &Preset, start_time: u32) -> Result<AuctionDetails, FusionOrderBuilderError> {
+    let (gas_bump_estimate, gas_price_estimate) = match &preset.gas_cost {
+        Some(gas_cost) => (
+            u32::try_from(gas_cost.gas_bump_estimate).map_err(|_| FusionOrderBuilderError::InvalidPreset("gas_bump_estimate"))?,
+            gas_cost.gas_price_estimate.parse().map_err(|_| FusionOrderBuilderError::InvalidPreset("gas_price_estimate"))?,
+        ),
+        None => (0, 0),
+    };
+
+    let details = AuctionDetails {
+        start_time,
+        duration: preset.auction_duration,
+        initial_rate_bump: preset.initial_rate_bump,
+        points: preset.points.clone(),
+        gas_bump_estimate,
+        gas_price_estimate,
+    };
+
+    // Checks every value against its packed width.
+    match details.encode() {
+        Ok(_) => Ok(details),
+        Err(FusionExtensionError::ValueTooWide { field, .. }) => Err(FusionOrderBuilderError::InvalidPreset(field)),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn now() -> u32 {
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    u32::try_from(seconds).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::bytes::Bytes,
+        orderbook::Extension,
+        signer::{LocalSigner, Signature},
+    };
+
+    const PRESET: &str = r#"{"auctionDuration":180,"startAuctionBy":12,"initialRateBump":50000,"auctionStartAmount":"105","startAmount":"100","auctionEndAmount":"95","exclusiveResolver":null,"points":[{"delay":60,"coefficient":25000}],"allowPartialFills":false,"allowMultipleFills":false,"gasCost":{"gasBumpEstimate":10,"gasPriceEstimate":"1000"}}"#;
+
+    fn quote() -> FusionQuoteResponse {
+        serde_json::from_str(&format!(
+            r#"{{"quoteId":"q-1","fromTokenAmount":"1000","toTokenAmount":"100","feeToken":"0x{a}","presets":{{"fast":{p},"medium":{p},"slow":{p},"custom":null}},"recommended_preset":"fast","prices":{{"usd":{{"fromToken":"1","toToken":"1"}}}},"volume":{{"usd":{{"fromToken":"1","toToken":"1"}}}},"settlementAddress":"0x{s}","whitelist":["0x{r}"]}}"#,
+            a = "aa".repeat(20),
+            s = "22".repeat(20),
+            r = "33".repeat(20),
+            p = PRESET
+        ))
+        .unwrap()
+    }
+
+    fn builder(quote: &FusionQuoteResponse) -> FusionOrderBuilder<'_> {
+        FusionOrderBuilder::new(quote, PresetKind::Fast)
+            .maker(Address::from_bytes([0x13; 20]))
+            .maker_asset(Address::from_bytes([0x55; 20]))
+            .taker_asset(Address::from_bytes([0x1d; 20]))
+            .auction_start_time(0x654f0c40)
+            .nonce(7)
+    }
+
+    #[test]
+    fn test_fusion_order_encoding() {
+        let quote = quote();
+        let order = builder(&quote).build().unwrap();
+
+        let settlement = "22".repeat(20);
+        let amount_data = format!("{}00000a000003e8654f0c400000b400c3500061a8003c", settlement);
+        let post_interaction = format!("{}08654f0c34{}0000", settlement, "33".repeat(10));
+
+        let extension = Extension::decode(&order.order.extension).unwrap();
+        assert_eq!(extension.making_amount_data, format!("0x{}", amount_data).parse::<Bytes>().unwrap());
+        assert_eq!(extension.taking_amount_data, extension.making_amount_data);
+        assert_eq!(extension.post_interaction, format!("0x{}", post_interaction).parse::<Bytes>().unwrap());
+        assert!(extension.predicate.is_empty());
+
+        let order = &order.order;
+        assert!(order.is_salt_valid());
+        assert_eq!(order.receiver, Address::ZERO);
+        assert_eq!(order.making_amount, 1000u64.into());
+        assert_eq!(order.taking_amount, 95u64.into());
+        assert_eq!(order.maker_traits.expiration(), 0x654f0c40 + 180);
+        assert_eq!(order.maker_traits.nonce_or_epoch(), 7);
+        assert!(!order.maker_traits.allow_partial_fills());
+        assert!(!order.maker_traits.allow_multiple_fills());
+        assert!(order.maker_traits.has_extension());
+        assert!(order.maker_traits.need_post_interaction_call());
+    }
+
+    #[test]
+    fn test_fusion_order_with_fee() {
+        let quote = quote();
+        let fee = IntegratorFee { ratio: 100, receiver: Address::from_bytes([0x44; 20]) };
+        let receiver = Address::from_bytes([0x66; 20]);
+        let order = builder(&quote).integrator_fee(fee).receiver(receiver).build().unwrap();
+
+        assert_eq!(order.order.receiver, quote.settlement_address);
+        let data = &order.extension.post_interaction_data;
+        assert_eq!(data.custom_receiver, Some(receiver));
+        assert_eq!(data.encode().unwrap()[0], 0b0000_1110);
+    }
+
+    #[test]
+    fn test_fusion_order_exclusive_resolver() {
+        let exclusive = Address::from_bytes([0x77; 20]);
+        let mut quote = quote();
+        quote.whitelist.push(exclusive);
+        quote.presets.fast.exclusive_resolver = Some(exclusive);
+        let order = builder(&quote).build().unwrap();
+
+        let data = &order.extension.post_interaction_data;
+        assert_eq!(data.resolving_start_time, 0x654f0c40 - 12);
+        assert_eq!(
+            data.whitelist,
+            vec![
+                WhitelistEntry { resolver: Address::from_bytes([0x33; 20]), allow_from: 0x654f0c40 },
+                WhitelistEntry { resolver: exclusive, allow_from: 0 },
+            ]
+        );
+
+        // The exclusive resolver goes first, the others 12 seconds after it.
+        let expected = format!("0x10654f0c34{}0000{}000c", "77".repeat(10), "33".repeat(10));
+        assert_eq!(data.encode().unwrap(), expected.parse::<Bytes>().unwrap());
+    }
+
+    #[test]
+    fn test_fusion_order_invalid_preset() {
+        let build_with = |edit: fn(&mut Preset)| {
+            let mut quote = quote();
+            edit(&mut quote.presets.fast);
+            builder(&quote).build().unwrap_err()
+        };
+
+        let error = build_with(|preset| preset.gas_cost.as_mut().unwrap().gas_bump_estimate = 1 << 24);
+        assert_eq!(error, FusionOrderBuilderError::InvalidPreset("gas_bump_estimate"));
+
+        let error = build_with(|preset| preset.auction_duration = 1 << 24);
+        assert_eq!(error, FusionOrderBuilderError::InvalidPreset("duration"));
+
+        let error = build_with(|preset| preset.points[0].coefficient = 1 << 24);
+        assert_eq!(error, FusionOrderBuilderError::InvalidPreset("point coefficient"));
+
+        let error = build_with(|preset| preset.points[0].delay = 1 << 16);
+        assert_eq!(error, FusionOrderBuilderError::InvalidPreset("point delay"));
+    }
+
+    #[test]
+    fn test_fusion_order_too_many_resolvers() {
+        let mut quote = quote();
+        quote.whitelist = vec![Address::from_bytes([0x33; 20]); 32];
+        let error = builder(&quote).build().unwrap_err();
+        assert_eq!(error, FusionOrderBuilderError::Extension(FusionExtensionError::TooManyResolvers(32)));
+    }
+
+    #[test]
+    fn test_fusion_order_errors() {
+        let quote = quote();
+        let error = FusionOrderBuilder::new(&quote, PresetKind::Custom).build().unwrap_err();
+        assert_eq!(error, FusionOrderBuilderError::MissingPreset(PresetKind::Custom));
+
+        let error = FusionOrderBuilder::new(&quote, PresetKind::Fast).build().unwrap_err();
+        assert_eq!(error, FusionOrderBuilderError::MissingField("maker"));
+    }
+
+    #[test]
+    fn test_signed_fusion_order() {
+        let quote = quote();
+        let signer = LocalSigner::random();
+        let order = builder(&quote).maker(signer.address()).build().unwrap();
+
+        let signed = order.sign(&signer, SupportedNetworks::Ethereum).unwrap();
+        assert_eq!(signed.quote_id, "q-1");
+        assert_eq!(signed.extension, order.order.extension);
+
+        let signature = Signature::from_bytes(&signed.signature).unwrap();
+        assert_eq!(
+            signature.recover(&order.order.hash(SupportedNetworks::Ethereum)).unwrap(),
+            signer.address()
+        );
+    }
+}
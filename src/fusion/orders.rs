@@ -0,0 +1,84 @@
+use crate::{
+    client::OneInchClient,
+    common::address::Address,
+    consts::FUSION_API_VERSION,
+    error::OneInchError,
+    fusion::{ActiveFusionOrdersResponse, FusionOrderStatusResponse, SettlementResponse},
+    utils::{
+        params::insert_optional_param,
+        request::{parse_url, parse_url_with_params},
+    },
+};
+use serde::Serialize;
+
+// Body of the request for statuses of several orders.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OrderHashes {
+    order_hashes: Vec<String>,
+}
+
+impl OneInchClient {
+    /// Performs request to get orders which are being auctioned right now.
+    pub async fn get_active_fusion_orders(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<ActiveFusionOrdersResponse, OneInchError> {
+        let url = format!("{}/fusion/orders/{}/{}/order/active", self.base_url, FUSION_API_VERSION, self.network_id);
+
+        self.get_json(parse_url_with_params(&url, pagination_params(page, limit))?).await
+    }
+
+    /// Performs request to get Fusion orders of the maker.
+    pub async fn get_fusion_orders_by_maker(
+        &self,
+        maker: Address,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<Vec<FusionOrderStatusResponse>, OneInchError> {
+        let url = format!(
+            "{}/fusion/orders/{}/{}/order/maker/{:#x}",
+            self.base_url, FUSION_API_VERSION, self.network_id, maker
+        );
+
+        self.get_json(parse_url_with_params(&url, pagination_params(page, limit))?).await
+    }
+
+    /// Performs request to get status of the order by its hash.
+    pub async fn get_fusion_order_status(&self, order_hash: &str) -> Result<FusionOrderStatusResponse, OneInchError> {
+        let url = format!(
+            "{}/fusion/orders/{}/{}/order/status/{}",
+            self.base_url, FUSION_API_VERSION, self.network_id, order_hash
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get statuses of several orders at once.
+    pub async fn get_fusion_orders_status(
+        &self,
+        order_hashes: Vec<String>,
+    ) -> Result<Vec<FusionOrderStatusResponse>, OneInchError> {
+        let url = format!("{}/fusion/orders/{}/{}/order/status", self.base_url, FUSION_API_VERSION, self.network_id);
+
+        self.post_json(parse_url(&url)?, &OrderHashes { order_hashes }).await
+    }
+
+    /// Performs request to get the settlement contract resolving the orders.
+    pub async fn get_fusion_settlement(&self) -> Result<SettlementResponse, OneInchError> {
+        let url = format!(
+            "{}/fusion/orders/{}/{}/order/settlement",
+            self.base_url, FUSION_API_VERSION, self.network_id
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+}
+
+fn pagination_params(page: Option<u32>, limit: Option<u32>) -> Vec<(&'static str, String)> {
+    let mut params: Vec<(&str, String)> = vec![];
+    insert_optional_param(&mut params, "page", page.map(|a| a.to_string()));
+    insert_optional_param(&mut params, "limit", limit.map(|a| a.to_string()));
+    params
+}
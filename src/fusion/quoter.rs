@@ -0,0 +1,48 @@
+use crate::{
+    client::OneInchClient,
+    consts::FUSION_API_VERSION,
+    error::OneInchError,
+    fusion::{CustomPreset, FusionQuoteDetails, FusionQuoteResponse},
+    utils::{params::insert_optional_param, request::parse_url_with_params},
+};
+
+impl OneInchClient {
+    /// Performs request to get Fusion quote with fast, medium and slow auction
+    /// presets.
+    pub async fn get_fusion_quote(&self, details: FusionQuoteDetails) -> Result<FusionQuoteResponse, OneInchError> {
+        let url = format!("{}/fusion/quoter/{}/{}/quote/receive", self.base_url, FUSION_API_VERSION, self.network_id);
+
+        self.get_json(parse_url_with_params(&url, fusion_quote_params(details))?).await
+    }
+
+    /// Performs request to get Fusion quote including the `custom` preset,
+    /// built from the provided auction parameters.
+    pub async fn get_fusion_quote_with_custom_preset(
+        &self,
+        details: FusionQuoteDetails,
+        preset: CustomPreset,
+    ) -> Result<FusionQuoteResponse, OneInchError> {
+        let url = format!("{}/fusion/quoter/{}/{}/quote/receive", self.base_url, FUSION_API_VERSION, self.network_id);
+
+        self.post_json(parse_url_with_params(&url, fusion_quote_params(details))?, &preset).await
+    }
+}
+
+fn fusion_quote_params(details: FusionQuoteDetails) -> Vec<(&'static str, String)> {
+    // Adding required parameters
+    let mut params: Vec<(&str, String)> = vec![
+        ("fromTokenAddress", format!("{:#x}", details.from_token_address)),
+        ("toTokenAddress", format!("{:#x}", details.to_token_address)),
+        ("amount", details.amount.to_string()),
+        ("walletAddress", format!("{:#x}", details.wallet_address)),
+    ];
+
+    // Adding optional parameters
+    insert_optional_param(&mut params, "enableEstimate", details.enable_estimate.map(|a| a.to_string()));
+    insert_optional_param(&mut params, "fee", details.fee.map(|a| a.to_string()));
+    insert_optional_param(&mut params, "isPermit2", details.is_permit2.map(|a| a.to_string()));
+    insert_optional_param(&mut params, "permit", details.permit);
+    insert_optional_param(&mut params, "source", details.source);
+
+    params
+}
@@ -0,0 +1,75 @@
+use crate::{
+    client::OneInchClient, consts::FUSION_API_VERSION, error::OneInchError, fusion::SignedFusionOrder, utils::request::parse_url,
+};
+use serde::de::IgnoredAny;
+
+impl OneInchClient {
+    /// Submits signed Fusion order to the relayer, which starts its auction.
+    /// The relayer doesn't return anything besides the status.
+    pub async fn submit_fusion_order(&self, order: SignedFusionOrder) -> Result<(), OneInchError> {
+        let url = format!("{}/fusion/relayer/{}/{}/order/submit", self.base_url, FUSION_API_VERSION, self.network_id);
+
+        self.post_json::<_, IgnoredAny>(parse_url(&url)?, &order).await.map(|_| ())
+    }
+
+    /// Submits several signed Fusion orders to the relayer at once.
+    pub async fn submit_fusion_orders(&self, orders: Vec<SignedFusionOrder>) -> Result<(), OneInchError> {
+        let url = format!(
+            "{}/fusion/relayer/{}/{}/order/submit/many",
+            self.base_url, FUSION_API_VERSION, self.network_id
+        );
+
+        self.post_json::<_, IgnoredAny>(parse_url(&url)?, &orders).await.map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::SupportedNetworks,
+        fusion::{AuctionPoint, FusionOrderBuilder, FusionQuoteDetailsBuilder, PresetKind},
+        signer::{LocalSigner, Signer},
+        transport::{testing::*, Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_fusion_with_mock() {
+        let preset = r#"{"auctionDuration":180,"startAuctionBy":1,"initialRateBump":50000,"auctionStartAmount":"105","startAmount":"100","auctionEndAmount":"95","exclusiveResolver":null,"costInDstToken":"1","points":[{"delay":60,"coefficient":25000}],"allowPartialFills":false,"allowMultipleFills":false,"gasCost":{"gasBumpEstimate":10,"gasPriceEstimate":"1000"}}"#;
+        let quote = format!(
+            r#"{{"quoteId":"q-1","fromTokenAmount":"1000","toTokenAmount":"100","feeToken":"{usdt}","presets":{{"fast":{p},"medium":{p},"slow":{p},"custom":null}},"recommended_preset":"medium","prices":{{"usd":{{"fromToken":"1","toToken":"0.5"}}}},"volume":{{"usd":{{"fromToken":"1000","toToken":"50"}}}},"settlementAddress":"{usdt}","whitelist":["{wallet}"]}}"#,
+            usdt = USDT,
+            wallet = WALLET,
+            p = preset
+        );
+
+        let transport = MockTransport::new();
+        transport
+            .register(Mock::get("/fusion/quoter/v2.0/56/quote/receive").query_param("walletAddress", WALLET).json_body(quote))
+            .register(Mock::post("/fusion/relayer/v2.0/56/order/submit").json_body(""));
+
+        let client = client_with(&transport);
+
+        let details = FusionQuoteDetailsBuilder::new()
+            .from_token_address(USDT.parse().unwrap())
+            .to_token_address(XRP.parse().unwrap())
+            .amount(1000u64.into())
+            .wallet_address(WALLET.parse().unwrap())
+            .build()
+            .unwrap();
+        let quote = client.get_fusion_quote(details.clone()).await.unwrap();
+        assert_eq!(quote.recommended_preset, PresetKind::Medium);
+        assert_eq!(quote.recommended().unwrap().points[0], AuctionPoint { delay: 60, coefficient: 25000 });
+        assert!(quote.presets.get(PresetKind::Custom).is_none());
+
+        let signer = LocalSigner::random();
+        let order =
+            FusionOrderBuilder::new(&quote, quote.recommended_preset).details(&details).maker(signer.address()).build().unwrap();
+        client.submit_fusion_order(order.sign(&signer, SupportedNetworks::BSC).unwrap()).await.unwrap();
+
+        let body: serde_json::Value = serde_json::from_slice(transport.requests()[1].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["quoteId"], "q-1");
+        assert_eq!(body["order"]["takingAmount"], "95");
+        assert_eq!(body["extension"], order.order.extension.to_string());
+        assert!(body["order"].get("extension").is_none());
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{
+    builder_setter,
+    common::{address::Address, amount::TokenAmount, bytes::Bytes},
+    orderbook::{LimitOrder, MakerTraits},
+    signer::Signature,
+    utils::builder::BasicBuilderError,
+};
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// Details of the Fusion quote request.
+#[derive(Debug, Clone)]
+pub struct FusionQuoteDetails {
+    pub from_token_address: Address,
+    pub to_token_address: Address,
+    pub amount: TokenAmount,
+    pub wallet_address: Address,
+
+    // Optional fields
+    pub enable_estimate: Option<bool>,
+    pub fee: Option<u32>,
+    pub is_permit2: Option<bool>,
+    pub permit: Option<String>,
+    pub source: Option<String>,
+}
+
+/// Builder to create instance of
+/// [`FusionQuoteDetails`](crate::fusion::FusionQuoteDetails).
+#[derive(Default)]
+pub struct FusionQuoteDetailsBuilder {
+    from_token_address: Option<Address>,
+    to_token_address: Option<Address>,
+    amount: Option<TokenAmount>,
+    wallet_address: Option<Address>,
+
+    enable_estimate: Option<bool>,
+    fee: Option<u32>,
+    is_permit2: Option<bool>,
+    permit: Option<String>,
+    source: Option<String>,
+}
+
+impl FusionQuoteDetailsBuilder {
+    pub fn new() -> Self {
+        FusionQuoteDetailsBuilder::default()
+    }
+
+    builder_setter!(from_token_address, Address);
+    builder_setter!(to_token_address, Address);
+    builder_setter!(amount, TokenAmount);
+    builder_setter!(wallet_address, Address);
+
+    builder_setter!(enable_estimate, bool);
+    builder_setter!(fee, u32);
+    builder_setter!(is_permit2, bool);
+    builder_setter!(permit, String);
+    builder_setter!(source, String);
+
+    /// Attempts to construct a
+    /// [`FusionQuoteDetails`](crate::fusion::FusionQuoteDetails) from the
+    /// builder, returning errors if required fields are missing.
+    pub fn build(self) -> Result<FusionQuoteDetails, BasicBuilderError> {
+        Ok(FusionQuoteDetails {
+            from_token_address: self.from_token_address.ok_or(BasicBuilderError::MissingField("from_token_address"))?,
+            to_token_address: self.to_token_address.ok_or(BasicBuilderError::MissingField("to_token_address"))?,
+            amount: self.amount.ok_or(BasicBuilderError::MissingField("amount"))?,
+            wallet_address: self.wallet_address.ok_or(BasicBuilderError::MissingField("wallet_address"))?,
+
+            enable_estimate: self.enable_estimate,
+            fee: self.fee,
+            is_permit2: self.is_permit2,
+            permit: self.permit,
+            source: self.source,
+        })
+    }
+}
+
+/// Auction preset, defining how fast the rate of the order decreases.
+#[derive(Serialize, Deserialize, Display, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum PresetKind {
+    Fast,
+    Medium,
+    Slow,
+    Custom,
+}
+
+/// Point of the auction curve: `delay` seconds after the previous point the
+/// rate bump is `coefficient`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuctionPoint {
+    pub delay: u32,
+    pub coefficient: u32,
+}
+
+/// Gas cost the resolvers are expected to pay, used to adjust the auction.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GasCost {
+    pub gas_bump_estimate: u64,
+    pub gas_price_estimate: String,
+}
+
+/// Auction parameters of the preset.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Preset {
+    /// Duration of the auction in seconds.
+    pub auction_duration: u32,
+
+    /// Delay in seconds before the auction starts.
+    pub start_auction_by: u32,
+
+    /// Rate bump the auction starts with, in 1/100000 units.
+    pub initial_rate_bump: u32,
+    pub auction_start_amount: TokenAmount,
+    pub start_amount: TokenAmount,
+    pub auction_end_amount: TokenAmount,
+    pub exclusive_resolver: Option<Address>,
+
+    #[serde(default)]
+    pub cost_in_dst_token: Option<TokenAmount>,
+    pub points: Vec<AuctionPoint>,
+    pub allow_partial_fills: bool,
+    pub allow_multiple_fills: bool,
+    pub gas_cost: Option<GasCost>,
+}
+
+/// Presets returned with the quote.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Presets {
+    pub fast: Preset,
+    pub medium: Preset,
+    pub slow: Preset,
+    pub custom: Option<Preset>,
+}
+
+impl Presets {
+    /// Returns the preset of the given kind, if it was returned.
+    pub fn get(&self, kind: PresetKind) -> Option<&Preset> {
+        match kind {
+            PresetKind::Fast => Some(&self.fast),
+            PresetKind::Medium => Some(&self.medium),
+            PresetKind::Slow => Some(&self.slow),
+            PresetKind::Custom => self.custom.as_ref(),
+        }
+    }
+}
+
+/// Values of the source and destination tokens.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenPair {
+    pub from_token: String,
+    pub to_token: String,
+}
+
+/// Values of the tokens in USD.
+#[derive(Deserialize, Debug, Clone)]
+pub struct PairCurrency {
+    pub usd: TokenPair,
+}
+
+/// Response of the Fusion quoter.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionQuoteResponse {
+    pub quote_id: Option<String>,
+    pub from_token_amount: TokenAmount,
+    pub to_token_amount: TokenAmount,
+    pub fee_token: Address,
+    pub presets: Presets,
+
+    // The only snake case field of the response.
+    #[serde(rename = "recommended_preset", alias = "recommendedPreset")]
+    pub recommended_preset: PresetKind,
+    pub prices: PairCurrency,
+    pub volume: PairCurrency,
+    pub settlement_address: Address,
+
+    /// Resolvers allowed to fill the order.
+    pub whitelist: Vec<Address>,
+}
+
+impl FusionQuoteResponse {
+    /// Returns the recommended preset.
+    pub fn recommended(&self) -> Option<&Preset> {
+        self.presets.get(self.recommended_preset)
+    }
+}
+
+/// Point of the custom auction curve.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomPresetPoint {
+    pub to_token_amount: TokenAmount,
+    pub delay: u32,
+}
+
+/// Custom auction parameters, sent to the quoter to get the `custom` preset.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomPreset {
+    pub auction_duration: u32,
+    pub auction_start_amount: TokenAmount,
+    pub auction_end_amount: TokenAmount,
+    pub points: Vec<CustomPresetPoint>,
+}
+
+/// Order in the form the Fusion API accepts and returns it. Unlike
+/// [`LimitOrder`] the extension is passed separately.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionOrderData {
+    pub salt: TokenAmount,
+    pub maker_asset: Address,
+    pub taker_asset: Address,
+    pub maker: Address,
+    pub receiver: Address,
+    pub making_amount: TokenAmount,
+    pub taking_amount: TokenAmount,
+    pub maker_traits: MakerTraits,
+}
+
+impl From<&LimitOrder> for FusionOrderData {
+    fn from(order: &LimitOrder) -> Self {
+        FusionOrderData {
+            salt: order.salt.clone(),
+            maker_asset: order.maker_asset,
+            taker_asset: order.taker_asset,
+            maker: order.maker,
+            receiver: order.receiver,
+            making_amount: order.making_amount.clone(),
+            taking_amount: order.taking_amount.clone(),
+            maker_traits: order.maker_traits.clone(),
+        }
+    }
+}
+
+/// Signed order submitted to the relayer.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedFusionOrder {
+    pub order: FusionOrderData,
+    pub signature: Bytes,
+    pub extension: Bytes,
+    pub quote_id: String,
+}
+
+impl SignedFusionOrder {
+    /// Creates the relayer payload for the order signed by the maker.
+    pub fn new(order: &LimitOrder, signature: &Signature, quote_id: impl Into<String>) -> Self {
+        SignedFusionOrder {
+            order: order.into(),
+            signature: (*signature).into(),
+            extension: order.extension.clone(),
+            quote_id: quote_id.into(),
+        }
+    }
+}
+
+/// Pagination info of the listing responses.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PaginationMeta {
+    pub total_items: u64,
+    pub items_per_page: u64,
+    pub total_pages: u64,
+    pub current_page: u64,
+}
+
+/// Order which is being auctioned right now.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveFusionOrder {
+    pub order_hash: String,
+    pub signature: Bytes,
+    pub deadline: String,
+    pub auction_start_date: String,
+    pub auction_end_date: String,
+    pub quote_id: String,
+    pub remaining_maker_amount: TokenAmount,
+    pub maker_balance: Option<TokenAmount>,
+    pub maker_allowance: Option<TokenAmount>,
+    #[serde(default)]
+    pub is_maker_contract: bool,
+    pub extension: Bytes,
+    pub order: FusionOrderData,
+}
+
+/// Page of the active orders.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ActiveFusionOrdersResponse {
+    pub meta: PaginationMeta,
+    pub items: Vec<ActiveFusionOrder>,
+}
+
+/// Status of the Fusion order.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FusionOrderStatus {
+    Pending,
+    Filled,
+    PartiallyFilled,
+    FalsePredicate,
+    NotEnoughBalanceOrAllowance,
+    Expired,
+    WrongPermit,
+    Cancelled,
+    InvalidSignature,
+
+    /// Any status not known to this crate yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Fill of the Fusion order.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionOrderFill {
+    pub tx_hash: String,
+    pub filled_maker_amount: TokenAmount,
+    pub filled_auction_taker_amount: TokenAmount,
+}
+
+/// Status of the order with its auction and fills.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FusionOrderStatusResponse {
+    pub order_hash: String,
+    pub status: FusionOrderStatus,
+    pub order: FusionOrderData,
+    pub extension: Bytes,
+    pub points: Option<Vec<AuctionPoint>>,
+    pub approximate_taking_amount: Option<TokenAmount>,
+    #[serde(default)]
+    pub fills: Vec<FusionOrderFill>,
+    pub auction_start_date: u64,
+    pub auction_duration: u64,
+    pub initial_rate_bump: u32,
+    pub is_native_currency: bool,
+    pub from_token_to_usd_price: Option<String>,
+    pub to_token_to_usd_price: Option<String>,
+    pub created_at: String,
+    pub cancel_tx: Option<String>,
+    #[serde(default)]
+    pub cancelable: bool,
+}
+
+/// Settlement contract resolving the Fusion orders.
+#[derive(Deserialize, Debug, Clone)]
+pub struct SettlementResponse {
+    pub address: Address,
+}
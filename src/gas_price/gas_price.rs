@@ -0,0 +1,45 @@
+use crate::{
+    client::OneInchClient, consts::GAS_PRICE_API_VERSION, error::OneInchError, gas_price::GasPriceResponse,
+    utils::request::parse_url,
+};
+
+impl OneInchClient {
+    /// Performs request to get current gas prices of the network.
+    pub async fn get_gas_price(&self) -> Result<GasPriceResponse, OneInchError> {
+        let url = format!("{}/gas-price/{}/{}", self.base_url, GAS_PRICE_API_VERSION, self.network_id);
+
+        self.get_json(parse_url(&url)?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        gas_price::GasTier,
+        swap::QuoteDetailsBuilder,
+        transport::{testing::*, Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_gas_price_with_mock() {
+        let transport = MockTransport::new();
+        transport
+            .register(
+                Mock::get("/gas-price/v1.4/56")
+                    .json_body(r#"{"low":"1000000000","medium":"3000000000","high":"5000000000","instant":"7000000000"}"#),
+            )
+            .register(Mock::get("/swap/v5.2/56/quote").query_param("gasPrice", "5000000000").json_body(r#"{"toAmount":"1"}"#));
+
+        let client = client_with(&transport);
+        let gas_price = client.get_gas_price().await.unwrap();
+
+        let details = QuoteDetailsBuilder::new()
+            .src(USDT.parse().unwrap())
+            .dst(XRP.parse().unwrap())
+            .amount(1000u64.into())
+            .gas_price_tier(&gas_price, GasTier::High)
+            .build()
+            .unwrap();
+        assert_eq!(client.quote(details).await.unwrap().to_amount, 1u64.into());
+    }
+}
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod gas_price;
+mod types;
+
+pub use types::*;
@@ -0,0 +1,134 @@
+use crate::common::amount::TokenAmount;
+use serde::Deserialize;
+use strum_macros::Display;
+
+/// Speed tier of the gas price.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[strum(serialize_all = "lowercase")]
+pub enum GasTier {
+    Low,
+    Medium,
+    High,
+    Instant,
+}
+
+/// EIP-1559 fees of the tier, in wei.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip1559Fee {
+    pub max_priority_fee_per_gas: TokenAmount,
+    pub max_fee_per_gas: TokenAmount,
+}
+
+/// Gas prices of the network supporting EIP-1559.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip1559GasPrice {
+    pub base_fee: TokenAmount,
+    pub low: Eip1559Fee,
+    pub medium: Eip1559Fee,
+    pub high: Eip1559Fee,
+    pub instant: Eip1559Fee,
+}
+
+/// Gas prices of the network without EIP-1559, in wei.
+#[derive(Deserialize, Debug, Clone)]
+pub struct LegacyGasPrice {
+    pub low: TokenAmount,
+    pub medium: TokenAmount,
+    pub high: TokenAmount,
+    pub instant: TokenAmount,
+}
+
+/// Response of [`get_gas_price`](crate::client::OneInchClient::get_gas_price),
+/// which shape depends on whether the network supports EIP-1559.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum GasPriceResponse {
+    Eip1559(Eip1559GasPrice),
+    Legacy(LegacyGasPrice),
+}
+
+impl GasPriceResponse {
+    /// Returns the gas price of the tier, as the swap API `gasPrice` parameter
+    /// expects it. For EIP-1559 networks it's the price transactions of the
+    /// tier are expected to pay: the base fee plus the priority fee, capped by
+    /// the max fee per gas. The max fee itself would overpay by the whole
+    /// headroom left for the base fee growth.
+    pub fn gas_price(&self, tier: GasTier) -> TokenAmount {
+        match self {
+            GasPriceResponse::Eip1559(prices) => {
+                let fee = prices.fee(tier);
+                match prices.base_fee.checked_add(&fee.max_priority_fee_per_gas) {
+                    Some(price) => price.min(fee.max_fee_per_gas.clone()),
+                    None => fee.max_fee_per_gas.clone(),
+                }
+            }
+            GasPriceResponse::Legacy(prices) => prices.price(tier).clone(),
+        }
+    }
+
+    /// Returns EIP-1559 fees of the tier, `None` for legacy networks.
+    pub fn eip1559_fee(&self, tier: GasTier) -> Option<&Eip1559Fee> {
+        match self {
+            GasPriceResponse::Eip1559(prices) => Some(prices.fee(tier)),
+            GasPriceResponse::Legacy(_) => None,
+        }
+    }
+}
+
+impl Eip1559GasPrice {
+    /// Returns fees of the tier.
+    pub fn fee(&self, tier: GasTier) -> &Eip1559Fee {
+        match tier {
+            GasTier::Low => &self.low,
+            GasTier::Medium => &self.medium,
+            GasTier::High => &self.high,
+            GasTier::Instant => &self.instant,
+        }
+    }
+}
+
+impl LegacyGasPrice {
+    /// Returns gas price of the tier.
+    pub fn price(&self, tier: GasTier) -> &TokenAmount {
+        match tier {
+            GasTier::Low => &self.low,
+            GasTier::Medium => &self.medium,
+            GasTier::High => &self.high,
+            GasTier::Instant => &self.instant,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_price_deserialization() {
+        let fee = |max: u64| format!(r#"{{"maxPriorityFeePerGas":"1000000000","maxFeePerGas":"{}"}}"#, max);
+        let json = format!(
+            r#"{{"baseFee":"20000000000","low":{},"medium":{},"high":{},"instant":{}}}"#,
+            fee(21_000_000_000),
+            fee(22_000_000_000),
+            fee(23_000_000_000),
+            fee(30_000_000_000)
+        );
+
+        let prices: GasPriceResponse = serde_json::from_str(&json).unwrap();
+        // Base fee plus the priority fee, below the max fee of the tier
+        assert_eq!(prices.gas_price(GasTier::High), 21_000_000_000u64.into());
+        // Capped by the max fee of the tier
+        assert_eq!(prices.gas_price(GasTier::Low), 21_000_000_000u64.into());
+        assert_eq!(
+            prices.eip1559_fee(GasTier::Instant).unwrap().max_priority_fee_per_gas,
+            1_000_000_000u64.into()
+        );
+
+        let json = r#"{"low":"1000000000","medium":"3000000000","high":"5000000000","instant":"7000000000"}"#;
+        let prices: GasPriceResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(prices.gas_price(GasTier::Medium), 3_000_000_000u64.into());
+        assert!(prices.eip1559_fee(GasTier::Medium).is_none());
+    }
+}
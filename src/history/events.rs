@@ -0,0 +1,122 @@
+use crate::{
+    client::OneInchClient,
+    common::address::Address,
+    consts::HISTORY_API_VERSION,
+    error::OneInchError,
+    history::{HistoryEvent, HistoryQuery, HistoryResponse},
+    utils::{params::insert_optional_param, request::parse_url_with_params},
+};
+use std::collections::HashSet;
+
+// Page size used to collect the history when the query has no limit.
+const DEFAULT_PAGE_LIMIT: u32 = 100;
+
+impl OneInchClient {
+    /// Performs request to get history events of the wallet, from the newest
+    /// to the oldest. Events of all the networks are returned unless the query
+    /// specifies the network.
+    pub async fn get_history_events(&self, wallet: Address, query: HistoryQuery) -> Result<HistoryResponse, OneInchError> {
+        let url = format!("{}/history/{}/history/{:#x}/events", self.base_url, HISTORY_API_VERSION, wallet);
+
+        let mut params = vec![];
+        insert_optional_param(&mut params, "limit", query.limit.map(|l| l.to_string()));
+        insert_optional_param(&mut params, "tokenAddress", query.token_address.map(|a| format!("{:#x}", a)));
+        insert_optional_param(&mut params, "chainId", query.chain.map(|c| c.to_string()));
+        insert_optional_param(&mut params, "fromTimestampMs", query.from_timestamp_ms.map(|t| t.to_string()));
+        insert_optional_param(&mut params, "toTimestampMs", query.to_timestamp_ms.map(|t| t.to_string()));
+
+        self.get_json(parse_url_with_params(&url, params)?).await
+    }
+
+    /// Performs requests to get all the history events of the wallet matching
+    /// the query, following the pages from the newest event to the oldest.
+    /// Events returned on several pages are deduplicated by `id`. Pages hold
+    /// `limit` events of the query, or 100 if it has no limit.
+    pub async fn get_all_history_events(&self, wallet: Address, query: HistoryQuery) -> Result<Vec<HistoryEvent>, OneInchError> {
+        let mut query = HistoryQuery { limit: Some(query.limit.unwrap_or(DEFAULT_PAGE_LIMIT)), ..query };
+        let mut seen = HashSet::new();
+        let mut events = vec![];
+
+        loop {
+            let page = self.get_history_events(wallet, query.clone()).await?;
+            let next = page.next_page(&query);
+
+            let collected = events.len();
+            events.extend(page.items.into_iter().filter(|event| seen.insert(event.id.clone())));
+
+            // A page bringing nothing new means the history is exhausted.
+            match next {
+                Some(next) if events.len() > collected => query = next,
+                _ => return Ok(events),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        client::SupportedNetworks,
+        history::HistoryQueryBuilder,
+        transport::{testing::*, Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_history_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(
+            Mock::get(format!("/history/v2.0/history/{}/events", WALLET))
+                .query_param("chainId", "56")
+                .query_param("limit", "5")
+                .json_body(r#"{"items":[]}"#),
+        );
+
+        let query = HistoryQueryBuilder::new().chain(SupportedNetworks::BSC).limit(5).unwrap().build().unwrap();
+        let history = client_with(&transport).get_history_events(WALLET.parse().unwrap(), query.clone()).await.unwrap();
+        assert!(history.items.is_empty());
+        assert!(history.next_page(&query).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_all_history_events_with_mock() {
+        let page = |events: &[(&str, u64)]| {
+            let items = events
+                .iter()
+                .map(|(id, time_ms)| {
+                    format!(
+                        r#"{{"id":"{id}","timeMs":{time_ms},"address":"{w}","rating":"Reliable","direction":"in","eventOrderInTransaction":0,"details":{{"txHash":"0x{id}","chainId":56,"blockNumber":1,"blockTimeSec":1,"status":"completed","type":"Transfer","tokenActions":[],"fromAddress":"{w}","toAddress":"{w}","orderInBlock":0,"nonce":0,"feeInWei":"0"}}}}"#,
+                        w = WALLET
+                    )
+                })
+                .collect::<Vec<_>>();
+            format!(r#"{{"items":[{}]}}"#, items.join(","))
+        };
+        let path = format!("/history/v2.0/history/{}/events", WALLET);
+
+        // Two events share the timestamp the first page ends at, and the second
+        // page is filled with that timestamp only.
+        let transport = MockTransport::new();
+        transport
+            .register(
+                Mock::get(path.clone())
+                    .query_param("limit", "2")
+                    .query_param("toTimestampMs", "4")
+                    .json_body(page(&[("b", 4), ("c", 4)])),
+            )
+            .register(
+                Mock::get(path.clone()).query_param("limit", "4").query_param("toTimestampMs", "4").json_body(page(&[
+                    ("b", 4),
+                    ("c", 4),
+                    ("d", 3),
+                ])),
+            )
+            .register(Mock::get(path).query_param("limit", "2").json_body(page(&[("a", 5), ("b", 4)])));
+
+        let query = HistoryQueryBuilder::new().limit(2).unwrap().build().unwrap();
+        let events = client_with(&transport).get_all_history_events(WALLET.parse().unwrap(), query).await.unwrap();
+
+        let ids: Vec<_> = events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c", "d"]);
+        assert_eq!(transport.requests().len(), 3);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    common::{address::Address, amount::TokenAmount},
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+// Largest page the history API returns.
+const MAX_HISTORY_LIMIT: u32 = 10000;
+
+/// Filters of the wallet history. The default value performs request without
+/// any filters.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryQuery {
+    /// Maximum number of events on the page. The API applies its own default
+    /// page size when it's not set.
+    pub limit: Option<u32>,
+    pub token_address: Option<Address>,
+    pub chain: Option<SupportedNetworks>,
+
+    /// Time range of the events as unix timestamps in milliseconds.
+    pub from_timestamp_ms: Option<u64>,
+    pub to_timestamp_ms: Option<u64>,
+}
+
+/// Enumerates potential errors when building `HistoryQuery`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum HistoryQueryBuilderError {
+    /// Indicates the limit is out of the allowed range.
+    #[error("Limit must be between 1 and 10000")]
+    InvalidLimit,
+
+    /// Indicates the time range ends before it starts.
+    #[error("Time range must start before it ends")]
+    InvalidTimeRange,
+}
+
+/// Builder to create instance of [`HistoryQuery`](crate::history::HistoryQuery).
+#[derive(Default)]
+pub struct HistoryQueryBuilder {
+    limit: Option<u32>,
+    token_address: Option<Address>,
+    chain: Option<SupportedNetworks>,
+    from_timestamp_ms: Option<u64>,
+    to_timestamp_ms: Option<u64>,
+}
+
+impl HistoryQueryBuilder {
+    pub fn new() -> Self {
+        HistoryQueryBuilder::default()
+    }
+
+    builder_setter!(token_address, Address);
+    builder_setter!(chain, SupportedNetworks);
+    builder_setter!(from_timestamp_ms, u64);
+    builder_setter!(to_timestamp_ms, u64);
+
+    /// Special setter for limit that ensures value is within allowable range.
+    pub fn limit(mut self, limit: u32) -> Result<Self, HistoryQueryBuilderError> {
+        if !(1..=MAX_HISTORY_LIMIT).contains(&limit) {
+            return Err(HistoryQueryBuilderError::InvalidLimit);
+        }
+        self.limit = Some(limit);
+        Ok(self)
+    }
+
+    /// Attempts to construct a [`HistoryQuery`](crate::history::HistoryQuery)
+    /// from the builder, returning error if the time range is invalid.
+    pub fn build(self) -> Result<HistoryQuery, HistoryQueryBuilderError> {
+        if let (Some(from), Some(to)) = (self.from_timestamp_ms, self.to_timestamp_ms) {
+            if from > to {
+                return Err(HistoryQueryBuilderError::InvalidTimeRange);
+            }
+        }
+
+        Ok(HistoryQuery {
+            limit: self.limit,
+            token_address: self.token_address,
+            chain: self.chain,
+            from_timestamp_ms: self.from_timestamp_ms,
+            to_timestamp_ms: self.to_timestamp_ms,
+        })
+    }
+}
+
+/// Kind of the transaction the event was produced by.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum HistoryEventType {
+    Transfer,
+    Approve,
+    SwapExactInput,
+    SwapExactOutput,
+    LimitOrderFill,
+    LimitOrderCancel,
+    LimitOrderCancelAll,
+    Wrap,
+    Unwrap,
+    Multicall,
+    Bridge,
+
+    /// Any type not known to this crate yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Direction of the token movement relative to the wallet.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenActionDirection {
+    In,
+    Out,
+
+    #[serde(rename = "Self")]
+    SelfTransfer,
+    On,
+
+    /// Any direction not known to this crate yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Token transferred, approved or otherwise touched by the transaction.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenAction {
+    pub address: Address,
+
+    /// Token standard, e.g. `ERC20` or `Native`.
+    pub standard: String,
+    pub from_address: Address,
+    pub to_address: Address,
+    pub amount: TokenAmount,
+    pub direction: TokenActionDirection,
+}
+
+/// Transaction behind the history event.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEventDetails {
+    pub tx_hash: String,
+    pub chain_id: u32,
+    pub block_number: u64,
+    pub block_time_sec: u64,
+
+    /// Transaction status, e.g. `completed` or `failed`.
+    pub status: String,
+
+    #[serde(rename = "type")]
+    pub event_type: HistoryEventType,
+    pub token_actions: Vec<TokenAction>,
+    pub from_address: Address,
+    pub to_address: Address,
+    pub order_in_block: u64,
+    pub nonce: u64,
+    pub fee_in_wei: TokenAmount,
+}
+
+/// Event of the wallet history.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEvent {
+    pub id: String,
+    pub time_ms: u64,
+    pub address: Address,
+
+    /// How reliable the event data is, e.g. `Reliable` or `Scam`.
+    pub rating: String,
+    pub direction: String,
+    pub details: HistoryEventDetails,
+    pub event_order_in_transaction: u32,
+}
+
+/// Page of the wallet history, from the newest event to the oldest.
+#[derive(Deserialize, Debug, Clone)]
+pub struct HistoryResponse {
+    pub items: Vec<HistoryEvent>,
+}
+
+impl HistoryResponse {
+    /// Returns query for the page following this one, or `None` if this page
+    /// is the last. Pages can only be detected as full when the query has a
+    /// limit, so without one the page is always treated as the last, even
+    /// though the API cuts it at its default page size.
+    ///
+    /// The next page ends at the oldest returned event inclusively, so events
+    /// sharing its timestamp are returned again and have to be deduplicated by
+    /// `id`, as [`get_all_history_events`](crate::client::OneInchClient::get_all_history_events)
+    /// does. When all the events of a full page share one timestamp, the next
+    /// page is requested with doubled limit instead, and `None` is returned
+    /// only if the limit is already the largest one allowed.
+    pub fn next_page(&self, query: &HistoryQuery) -> Option<HistoryQuery> {
+        let limit = query.limit?;
+        if (self.items.len() as u32) < limit {
+            return None;
+        }
+
+        let newest = self.items.first()?.time_ms;
+        let oldest = self.items.last()?.time_ms;
+
+        // The bound can't move past a single timestamp, so the page grows.
+        let limit = if newest != oldest {
+            limit
+        } else if limit < MAX_HISTORY_LIMIT {
+            limit.saturating_mul(2).min(MAX_HISTORY_LIMIT)
+        } else {
+            return None;
+        };
+
+        Some(HistoryQuery { limit: Some(limit), to_timestamp_ms: Some(oldest), ..query.clone() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HISTORY_JSON: &str = r#"{"items": [{
+        "timeMs": 1700000000000,
+        "address": "0x13961a09bcd42dcc078765286be746d87f20e82e",
+        "type": 0,
+        "rating": "Reliable",
+        "direction": "in",
+        "details": {
+            "txHash": "0xabc",
+            "chainId": 56,
+            "blockNumber": 33000000,
+            "blockTimeSec": 1700000000,
+            "status": "completed",
+            "type": "SwapExactInput",
+            "tokenActions": [{
+                "chainId": "56",
+                "address": "0x55d398326f99059ff775485246999027b3197955",
+                "standard": "ERC20",
+                "fromAddress": "0x13961a09bcd42dcc078765286be746d87f20e82e",
+                "toAddress": "0x111111125421ca6dc452d289314280a0f8842a65",
+                "amount": "1000",
+                "direction": "Out"
+            }],
+            "fromAddress": "0x13961a09bcd42dcc078765286be746d87f20e82e",
+            "toAddress": "0x111111125421ca6dc452d289314280a0f8842a65",
+            "orderInBlock": 12,
+            "nonce": 5,
+            "feeInWei": "300000000000000"
+        },
+        "id": "1",
+        "eventOrderInTransaction": 0
+    }]}"#;
+
+    #[test]
+    fn test_history_query_builder() {
+        assert_eq!(HistoryQueryBuilder::new().limit(0).err(), Some(HistoryQueryBuilderError::InvalidLimit));
+        assert_eq!(
+            HistoryQueryBuilder::new().from_timestamp_ms(2).to_timestamp_ms(1).build().err(),
+            Some(HistoryQueryBuilderError::InvalidTimeRange)
+        );
+
+        let query = HistoryQueryBuilder::new().limit(10).unwrap().chain(SupportedNetworks::BSC).build().unwrap();
+        assert_eq!(query.limit, Some(10));
+        assert!(query.token_address.is_none());
+    }
+
+    #[test]
+    fn test_history_deserialization() {
+        let json = HISTORY_JSON;
+
+        let history: HistoryResponse = serde_json::from_str(json).unwrap();
+        let event = &history.items[0];
+        assert_eq!(event.details.event_type, HistoryEventType::SwapExactInput);
+        assert_eq!(event.details.token_actions[0].direction, TokenActionDirection::Out);
+        assert_eq!(event.details.token_actions[0].amount, 1000u64.into());
+
+        let query = HistoryQuery { limit: Some(1), ..Default::default() };
+        let next = history.next_page(&query).unwrap();
+        assert_eq!(next.to_timestamp_ms, Some(1700000000000));
+        assert_eq!(next.limit, Some(2));
+        assert!(history.next_page(&HistoryQuery { limit: Some(2), ..Default::default() }).is_none());
+        assert!(history.next_page(&HistoryQuery::default()).is_none());
+    }
+
+    #[test]
+    fn test_history_pagination_terminates() {
+        let event: serde_json::Value = serde_json::from_str::<serde_json::Value>(HISTORY_JSON).unwrap()["items"][0].clone();
+        let events: Vec<HistoryEvent> = [5u64, 4, 4, 4, 3, 1]
+            .iter()
+            .enumerate()
+            .map(|(id, time_ms)| {
+                let mut event = event.clone();
+                event["id"] = id.to_string().into();
+                event["timeMs"] = (*time_ms).into();
+                serde_json::from_value(event).unwrap()
+            })
+            .collect();
+
+        // Emulates the API: events up to the bound inclusively, newest first.
+        let fetch = |query: &HistoryQuery| HistoryResponse {
+            items: events
+                .iter()
+                .filter(|e| query.to_timestamp_ms.is_none_or(|to| e.time_ms <= to))
+                .take(query.limit.unwrap() as usize)
+                .cloned()
+                .collect(),
+        };
+
+        let mut query = HistoryQuery { limit: Some(2), ..Default::default() };
+        let mut seen = vec![];
+        for _ in 0..10 {
+            let page = fetch(&query);
+            for event in &page.items {
+                if !seen.contains(&event.id) {
+                    seen.push(event.id.clone());
+                }
+            }
+            match page.next_page(&query) {
+                Some(next) => query = next,
+                None => break,
+            }
+        }
+
+        assert_eq!(seen, vec!["0", "1", "2", "3", "4", "5"]);
+        assert!(fetch(&query).next_page(&query).is_none());
+    }
+}
@@ -13,6 +13,19 @@ extern crate core;
 /// Offers methods to make requests to the API and handle responses.
 pub mod client;
 
+/// Errors returned by the client.
+pub mod error;
+
+/// Policy describing how failed requests are retried.
+pub mod retry;
+
+/// Client-side limiter keeping requests under the API key quota.
+pub mod rate_limit;
+
+/// Transports used by the client to send requests, including an in-memory
+/// mock for offline testing.
+pub mod transport;
+
 // Constants used across the crate, including API namespace versions, and a
 // basic url.
 mod consts;
@@ -24,9 +37,43 @@ pub(crate) mod utils;
 /// optimal swap routes and executing swap transactions.
 pub mod swap;
 
+/// Limit orders of the 1inch Limit Order Protocol v4: typed orders and the
+/// orderbook API v4.0 endpoints.
+pub mod orderbook;
+
+/// Fusion intent-based swaps: quoter, relayer and orders API endpoints.
+pub mod fusion;
+
+/// Signing of orders, permits and messages with a local private key or any
+/// other `Signer` implementation.
+pub mod signer;
+
+/// Legacy and EIP-1559 transactions built from swap and approve responses,
+/// ready to be signed and broadcasted.
+pub mod transaction;
+
 /// Common structures definitions shared by other modules.
 pub mod common;
 
 /// Modules related to tokens, including retrieving supported currencies, token
 /// metadata, getting its price.
 pub mod tokens;
+
+/// Token balances and allowances of one or multiple wallets.
+pub mod balance;
+
+/// Current gas prices of the network, legacy or EIP-1559 ones.
+pub mod gas_price;
+
+/// Transaction history of wallets across the networks.
+pub mod history;
+
+/// Block and transaction traces, parsed into call trees.
+pub mod traces;
+
+/// Portfolio of wallets across the networks: value, profit and loss and
+/// breakdowns by token and protocol.
+pub mod portfolio;
+
+/// NFTs owned by addresses and their metadata.
+pub mod nft;
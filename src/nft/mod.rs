@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod nft;
+mod types;
+
+pub use types::*;
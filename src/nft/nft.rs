@@ -0,0 +1,74 @@
+use crate::{
+    client::{OneInchClient, SupportedNetworks},
+    common::address::Address,
+    consts::NFT_API_VERSION,
+    error::OneInchError,
+    nft::{Nft, NftsByAddressQuery, NftsByAddressResponse},
+    utils::{
+        params::insert_optional_param,
+        request::{parse_url, parse_url_with_params},
+    },
+};
+
+impl OneInchClient {
+    /// Performs request to get chain ids of the networks the NFT API
+    /// supports.
+    pub async fn get_nft_supported_chains(&self) -> Result<Vec<u32>, OneInchError> {
+        let url = format!("{}/nft/{}/supportedchains", self.base_url, NFT_API_VERSION);
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get NFTs owned by the address in the query
+    /// networks.
+    pub async fn get_nfts_by_address(&self, query: NftsByAddressQuery) -> Result<NftsByAddressResponse, OneInchError> {
+        let url = format!("{}/nft/{}/byaddress", self.base_url, NFT_API_VERSION);
+
+        let mut params: Vec<(&str, String)> = query.chains.iter().map(|chain| ("chainIds", chain.to_string())).collect();
+        params.push(("address", format!("{:#x}", query.address)));
+        insert_optional_param(&mut params, "limit", query.limit.map(|l| l.to_string()));
+        insert_optional_param(&mut params, "offset", query.offset.map(|o| o.to_string()));
+
+        self.get_json(parse_url_with_params(&url, params)?).await
+    }
+
+    /// Performs request to get metadata of the NFT by its contract and token
+    /// id.
+    pub async fn get_nft(&self, chain: SupportedNetworks, contract: Address, token_id: &str) -> Result<Nft, OneInchError> {
+        let url = format!("{}/nft/{}/getNft", self.base_url, NFT_API_VERSION);
+
+        let params = [("chainId", chain.to_string()), ("contract", format!("{:#x}", contract)), ("id", token_id.to_string())];
+
+        self.get_json(parse_url_with_params(&url, params)?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nft::NftsByAddressQueryBuilder,
+        transport::{testing::*, Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_nft_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(Mock::get("/nft/v1/supportedchains").json_body("[1,56,137]")).register(
+            Mock::get("/nft/v1/byaddress")
+                .query_param("chainIds", "56")
+                .query_param("address", WALLET)
+                .json_body(r#"{"assets":[]}"#),
+        );
+
+        let client = client_with(&transport);
+        assert!(client.get_nft_supported_chains().await.unwrap().contains(&56));
+
+        let query = NftsByAddressQueryBuilder::new()
+            .address(WALLET.parse().unwrap())
+            .chains(vec![SupportedNetworks::BSC])
+            .build()
+            .unwrap();
+        assert!(client.get_nfts_by_address(query).await.unwrap().assets.is_empty());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{builder_setter, client::SupportedNetworks, common::address::Address, utils::builder::BasicBuilderError};
+use serde::Deserialize;
+
+/// Filters and pagination of the NFTs owned by the address.
+#[derive(Debug, Clone)]
+pub struct NftsByAddressQuery {
+    pub address: Address,
+
+    /// Networks to look up the NFTs in.
+    pub chains: Vec<SupportedNetworks>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// Builder to create instance of
+/// [`NftsByAddressQuery`](crate::nft::NftsByAddressQuery).
+#[derive(Default)]
+pub struct NftsByAddressQueryBuilder {
+    address: Option<Address>,
+    chains: Option<Vec<SupportedNetworks>>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+impl NftsByAddressQueryBuilder {
+    pub fn new() -> Self {
+        NftsByAddressQueryBuilder::default()
+    }
+
+    builder_setter!(address, Address);
+    builder_setter!(chains, Vec<SupportedNetworks>);
+    builder_setter!(limit, u32);
+    builder_setter!(offset, u32);
+
+    /// Attempts to construct a
+    /// [`NftsByAddressQuery`](crate::nft::NftsByAddressQuery) from the
+    /// builder, returning errors if required fields are missing.
+    pub fn build(self) -> Result<NftsByAddressQuery, BasicBuilderError> {
+        Ok(NftsByAddressQuery {
+            address: self.address.ok_or(BasicBuilderError::MissingField("address"))?,
+            chains: self.chains.filter(|c| !c.is_empty()).ok_or(BasicBuilderError::MissingField("chains"))?,
+            limit: self.limit,
+            offset: self.offset,
+        })
+    }
+}
+
+/// Contract of the NFT.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AssetContract {
+    pub address: Address,
+    pub name: Option<String>,
+
+    /// Token standard, e.g. `ERC721` or `ERC1155`.
+    pub schema_name: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// Collection the NFT belongs to.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Collection {
+    pub name: Option<String>,
+    pub slug: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// Attribute of the NFT. Values are either strings or numbers.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NftTrait {
+    pub trait_type: Option<String>,
+    pub value: serde_json::Value,
+    pub display_type: Option<String>,
+}
+
+/// NFT with its metadata and media.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Nft {
+    pub id: Option<serde_json::Value>,
+    pub token_id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+
+    #[serde(rename = "chainId")]
+    pub chain_id: u32,
+
+    /// Marketplace the metadata comes from, e.g. `OPENSEA` or `RARIBLE`.
+    pub provider: Option<String>,
+    pub image_url: Option<String>,
+    pub image_preview_url: Option<String>,
+    pub animation_url: Option<String>,
+    pub permalink: Option<String>,
+    pub asset_contract: AssetContract,
+    pub collection: Option<Collection>,
+
+    #[serde(default)]
+    pub traits: Vec<NftTrait>,
+}
+
+/// NFTs owned by the address.
+#[derive(Deserialize, Debug, Clone)]
+pub struct NftsByAddressResponse {
+    pub assets: Vec<Nft>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nft_deserialization() {
+        let json = r#"{"assets": [{
+            "id": 1,
+            "token_id": "1234",
+            "name": "Punk #1234",
+            "description": null,
+            "chainId": 1,
+            "provider": "OPENSEA",
+            "image_url": "https://example.com/1234.png",
+            "animation_url": null,
+            "asset_contract": {
+                "address": "0xb47e3cd837ddf8e4c57f05d70ab865de6e193bbb",
+                "name": "CryptoPunks",
+                "schema_name": "ERC721",
+                "image_url": null
+            },
+            "collection": {"name": "CryptoPunks", "slug": "cryptopunks"},
+            "traits": [
+                {"trait_type": "Type", "value": "Alien", "display_type": null},
+                {"trait_type": "Accessories", "value": 2, "display_type": "number"}
+            ]
+        }]}"#;
+
+        let response: NftsByAddressResponse = serde_json::from_str(json).unwrap();
+        let nft = &response.assets[0];
+        assert_eq!(nft.token_id, "1234");
+        assert_eq!(nft.asset_contract.schema_name.as_deref(), Some("ERC721"));
+        assert_eq!(nft.traits[1].value, 2);
+        assert!(nft.image_preview_url.is_none());
+
+        assert_eq!(
+            NftsByAddressQueryBuilder::new().build().err(),
+            Some(BasicBuilderError::MissingField("address"))
+        );
+    }
+}
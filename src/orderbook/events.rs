@@ -0,0 +1,33 @@
+use crate::{
+    client::OneInchClient,
+    consts::ORDERBOOK_API_VERSION,
+    error::OneInchError,
+    orderbook::OrderEvent,
+    utils::{
+        params::insert_optional_param,
+        request::{parse_url, parse_url_with_params},
+    },
+};
+
+impl OneInchClient {
+    /// Performs request to get fill and cancel events of the order.
+    pub async fn get_order_events(&self, order_hash: &str) -> Result<Vec<OrderEvent>, OneInchError> {
+        let url = format!(
+            "{}/orderbook/{}/{}/events/{}",
+            self.base_url, ORDERBOOK_API_VERSION, self.network_id, order_hash
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get the latest fill and cancel events of all the
+    /// orders.
+    pub async fn get_all_order_events(&self, limit: Option<u32>) -> Result<Vec<OrderEvent>, OneInchError> {
+        let url = format!("{}/orderbook/{}/{}/events", self.base_url, ORDERBOOK_API_VERSION, self.network_id);
+
+        let mut params: Vec<(&str, String)> = vec![];
+        insert_optional_param(&mut params, "limit", limit.map(|a| a.to_string()));
+
+        self.get_json(parse_url_with_params(&url, params)?).await
+    }
+}
@@ -0,0 +1,189 @@
+use crate::common::{address::Address, bytes::Bytes};
+use thiserror::Error;
+
+/// Call to an external contract made while the order is filled, encoded as
+/// the target address followed by the calldata.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Interaction {
+    pub target: Address,
+    pub data: Bytes,
+}
+
+/// Order extension of the Limit Order Protocol v4, containing everything that
+/// doesn't fit into the order struct itself. The keccak hash of the encoded
+/// extension is bound to the order through its salt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Extension {
+    pub maker_asset_suffix: Bytes,
+    pub taker_asset_suffix: Bytes,
+    pub making_amount_data: Bytes,
+    pub taking_amount_data: Bytes,
+    pub predicate: Bytes,
+    pub maker_permit: Bytes,
+    pub pre_interaction: Bytes,
+    pub post_interaction: Bytes,
+    pub custom_data: Bytes,
+}
+
+/// Enumerates potential errors when decoding `Interaction` or `Extension`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum ExtensionError {
+    /// Indicates the data is shorter than its encoding requires.
+    #[error("Encoded data is too short")]
+    TooShort,
+
+    /// Indicates the offsets don't match the length of the data.
+    #[error("Invalid offsets in encoded extension")]
+    InvalidOffsets,
+}
+
+impl Interaction {
+    /// Creates new interaction calling `target` with `data`.
+    pub fn new(target: Address, data: Bytes) -> Self {
+        Interaction { target, data }
+    }
+
+    /// Encodes the interaction as `target ++ data`.
+    pub fn encode(&self) -> Bytes {
+        let mut encoded = self.target.as_bytes().to_vec();
+        encoded.extend_from_slice(&self.data);
+        encoded.into()
+    }
+
+    /// Decodes the interaction from `target ++ data`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, ExtensionError> {
+        if bytes.len() < 20 {
+            return Err(ExtensionError::TooShort);
+        }
+
+        let mut target = [0u8; 20];
+        target.copy_from_slice(&bytes[..20]);
+
+        Ok(Interaction { target: target.into(), data: bytes[20..].into() })
+    }
+}
+
+impl Extension {
+    /// Returns true if none of the extension fields are set.
+    pub fn is_empty(&self) -> bool {
+        self.fields().iter().all(|field| field.is_empty()) && self.custom_data.is_empty()
+    }
+
+    /// Encodes the extension the way the protocol expects it: 32 bytes of
+    /// packed end offsets of the first eight fields, followed by all the
+    /// fields and the custom data. Empty extension is encoded as empty bytes.
+    pub fn encode(&self) -> Bytes {
+        if self.is_empty() {
+            return Bytes::new();
+        }
+
+        let fields = self.fields();
+        let mut offsets = [0u8; 32];
+        let mut end = 0u32;
+
+        // Offset of the i-th field is stored in bits [32 * i, 32 * (i + 1)).
+        for (i, field) in fields.iter().enumerate() {
+            end += field.len() as u32;
+            offsets[28 - i * 4..32 - i * 4].copy_from_slice(&end.to_be_bytes());
+        }
+
+        let mut encoded = offsets.to_vec();
+        fields.iter().for_each(|field| encoded.extend_from_slice(field));
+        encoded.extend_from_slice(&self.custom_data);
+        encoded.into()
+    }
+
+    /// Decodes the extension encoded with [`Extension::encode`].
+    pub fn decode(bytes: &[u8]) -> Result<Self, ExtensionError> {
+        if bytes.is_empty() {
+            return Ok(Extension::default());
+        }
+        if bytes.len() < 32 {
+            return Err(ExtensionError::TooShort);
+        }
+
+        let (offsets, data) = bytes.split_at(32);
+        let mut fields: Vec<Bytes> = Vec::with_capacity(8);
+        let mut start = 0usize;
+
+        for i in 0..8 {
+            let mut end = [0u8; 4];
+            end.copy_from_slice(&offsets[28 - i * 4..32 - i * 4]);
+            let end = u32::from_be_bytes(end) as usize;
+
+            if end < start || end > data.len() {
+                return Err(ExtensionError::InvalidOffsets);
+            }
+
+            fields.push(data[start..end].into());
+            start = end;
+        }
+
+        let mut fields = fields.into_iter();
+        let mut next = || fields.next().unwrap_or_default();
+
+        Ok(Extension {
+            maker_asset_suffix: next(),
+            taker_asset_suffix: next(),
+            making_amount_data: next(),
+            taking_amount_data: next(),
+            predicate: next(),
+            maker_permit: next(),
+            pre_interaction: next(),
+            post_interaction: next(),
+            custom_data: data[start..].into(),
+        })
+    }
+
+    // Fields covered by the offsets, in the order they are encoded.
+    fn fields(&self) -> [&Bytes; 8] {
+        [
+            &self.maker_asset_suffix,
+            &self.taker_asset_suffix,
+            &self.making_amount_data,
+            &self.taking_amount_data,
+            &self.predicate,
+            &self.maker_permit,
+            &self.pre_interaction,
+            &self.post_interaction,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extension_encoding() {
+        assert!(Extension::default().encode().is_empty());
+
+        let extension = Extension {
+            predicate: "0x0102".parse().unwrap(),
+            post_interaction: Interaction::new(Address::from_bytes([0x11; 20]), "0xff".parse().unwrap()).encode(),
+            custom_data: "0xaa".parse().unwrap(),
+            ..Default::default()
+        };
+
+        let encoded = extension.encode();
+        // Field ends: 0, 0, 0, 0, 2, 2, 2, 23
+        let offsets: Bytes = "0x0000001700000002000000020000000200000000000000000000000000000000".parse().unwrap();
+        assert_eq!(encoded[..32], offsets[..]);
+        assert_eq!(encoded.len(), 32 + 2 + 21 + 1);
+        assert_eq!(Extension::decode(&encoded).unwrap(), extension);
+
+        let interaction = Interaction::decode(&Extension::decode(&encoded).unwrap().post_interaction).unwrap();
+        assert_eq!(interaction.target, Address::from_bytes([0x11; 20]));
+        assert_eq!(interaction.data.to_vec(), vec![0xff]);
+    }
+
+    #[test]
+    fn test_invalid_extension() {
+        assert_eq!(Extension::decode(&[0u8; 31]), Err(ExtensionError::TooShort));
+
+        let mut encoded = [0u8; 32];
+        encoded[31] = 1;
+        assert_eq!(Extension::decode(&encoded), Err(ExtensionError::InvalidOffsets));
+        assert_eq!(Interaction::decode(&[0u8; 19]), Err(ExtensionError::TooShort));
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    common::{
+        address::Address,
+        amount::TokenAmount,
+        eip712::{encode_address, encode_uint, Eip712, Eip712Domain},
+    },
+    consts::{LIMIT_ORDER_PROTOCOL_ADDRESS, LIMIT_ORDER_PROTOCOL_ZKSYNC_ADDRESS},
+    orderbook::{Extension, LimitOrder, MakerTraits},
+    utils::keccak::keccak256,
+};
+use num_bigint::BigUint;
+use thiserror::Error;
+
+// Name and version of the Limit Order Protocol v4 EIP-712 domain.
+const DOMAIN_NAME: &str = "1inch Aggregation Router";
+const DOMAIN_VERSION: &str = "6";
+
+// Number of the low salt bits holding the extension hash.
+const SALT_EXTENSION_HASH_BITS: u32 = 160;
+
+/// Returns address of the Limit Order Protocol contract in the network.
+pub fn limit_order_protocol_address(network: SupportedNetworks) -> Address {
+    let address = match network {
+        SupportedNetworks::ZkSync => LIMIT_ORDER_PROTOCOL_ZKSYNC_ADDRESS,
+        _ => LIMIT_ORDER_PROTOCOL_ADDRESS,
+    };
+
+    address.parse().expect("Limit Order Protocol addresses are valid")
+}
+
+/// Returns EIP-712 domain the limit orders are signed for in the network.
+pub fn limit_order_protocol_domain(network: SupportedNetworks) -> Eip712Domain {
+    Eip712Domain::new(
+        DOMAIN_NAME,
+        DOMAIN_VERSION,
+        network.chain_id().into(),
+        limit_order_protocol_address(network),
+    )
+}
+
+impl Eip712 for LimitOrder {
+    fn type_string() -> &'static str {
+        "Order(uint256 salt,address maker,address receiver,address makerAsset,address takerAsset,uint256 makingAmount,uint256 takingAmount,uint256 makerTraits)"
+    }
+
+    fn encode_data(&self) -> Vec<[u8; 32]> {
+        vec![
+            encode_uint(&self.salt),
+            encode_address(&self.maker),
+            encode_address(&self.receiver),
+            encode_address(&self.maker_asset),
+            encode_address(&self.taker_asset),
+            encode_uint(&self.making_amount),
+            encode_uint(&self.taking_amount),
+            self.maker_traits.to_be_bytes(),
+        ]
+    }
+}
+
+impl LimitOrder {
+    /// Computes the order hash, which is signed by the maker and identifies
+    /// the order in the orderbook.
+    pub fn hash(&self, network: SupportedNetworks) -> [u8; 32] {
+        self.signing_hash(&limit_order_protocol_domain(network))
+    }
+
+    /// Same as [`LimitOrder::hash`] but returns `0x`-prefixed hex string, as
+    /// the orderbook API expects it.
+    pub fn hash_hex(&self, network: SupportedNetworks) -> String {
+        format!("0x{}", self.hash(network).iter().map(|b| format!("{:02x}", b)).collect::<String>())
+    }
+
+    /// Returns true if the low bits of the salt match the hash of the order
+    /// extension, as the protocol requires for orders with extension.
+    pub fn is_salt_valid(&self) -> bool {
+        self.extension.is_empty() || salt_extension_bits(self.salt.as_biguint()) == extension_hash_bits(&self.extension)
+    }
+}
+
+/// Enumerates potential errors when building `LimitOrder`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum LimitOrderBuilderError {
+    /// Indicates a required field is missing its value.
+    #[error("Missing {0}")]
+    MissingField(&'static str),
+
+    /// Indicates the provided salt doesn't match the order extension.
+    #[error("Low 160 bits of the salt must match the extension hash")]
+    InvalidSalt,
+}
+
+/// Builder to create instance of [`LimitOrder`](crate::orderbook::LimitOrder).
+///
+/// Unless provided explicitly, the salt is generated randomly, with its low
+/// 160 bits set to the extension hash. Extension related flags of the maker
+/// traits are set according to the extension.
+///
+/// ```
+/// use one_inch::{client::SupportedNetworks, orderbook::{LimitOrderBuilder, MakerTraits}};
+///
+/// let order = LimitOrderBuilder::new()
+///     .maker("0x2c7536e3605d9c16a7a3d7b1898e529396a65c23".parse().unwrap())
+///     .maker_asset("0xdac17f958d2ee523a2206206994597c13d831ec7".parse().unwrap())
+///     .taker_asset("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2".parse().unwrap())
+///     .making_amount(1_000_000u64.into())
+///     .taking_amount(1_000_000_000_000_000u64.into())
+///     .maker_traits(MakerTraits::default().with_expiration(1_700_000_000).unwrap())
+///     .build()
+///     .unwrap();
+///
+/// let order_hash = order.hash_hex(SupportedNetworks::Ethereum);
+/// ```
+#[derive(Default)]
+pub struct LimitOrderBuilder {
+    salt: Option<TokenAmount>,
+    maker: Option<Address>,
+    receiver: Option<Address>,
+    maker_asset: Option<Address>,
+    taker_asset: Option<Address>,
+    making_amount: Option<TokenAmount>,
+    taking_amount: Option<TokenAmount>,
+    maker_traits: Option<MakerTraits>,
+    extension: Option<Extension>,
+}
+
+impl LimitOrderBuilder {
+    pub fn new() -> Self {
+        LimitOrderBuilder::default()
+    }
+
+    builder_setter!(salt, TokenAmount);
+    builder_setter!(maker, Address);
+    builder_setter!(receiver, Address);
+    builder_setter!(maker_asset, Address);
+    builder_setter!(taker_asset, Address);
+    builder_setter!(making_amount, TokenAmount);
+    builder_setter!(taking_amount, TokenAmount);
+    builder_setter!(maker_traits, MakerTraits);
+    builder_setter!(extension, Extension);
+
+    /// Attempts to construct a [`LimitOrder`](crate::orderbook::LimitOrder)
+    /// from the builder, returning errors if required fields are missing or if
+    /// the salt doesn't match the extension.
+    pub fn build(self) -> Result<LimitOrder, LimitOrderBuilderError> {
+        let extension = self.extension.unwrap_or_default();
+        let encoded_extension = extension.encode();
+
+        let mut maker_traits = self.maker_traits.unwrap_or_default().with_extension(!encoded_extension.is_empty());
+        if !extension.pre_interaction.is_empty() {
+            maker_traits = maker_traits.with_pre_interaction_call(true);
+        }
+        if !extension.post_interaction.is_empty() {
+            maker_traits = maker_traits.with_post_interaction_call(true);
+        }
+
+        let order = LimitOrder {
+            salt: self.salt.unwrap_or_else(|| generate_salt(&encoded_extension)),
+            maker: self.maker.ok_or(LimitOrderBuilderError::MissingField("maker"))?,
+            receiver: self.receiver.unwrap_or(Address::ZERO),
+            maker_asset: self.maker_asset.ok_or(LimitOrderBuilderError::MissingField("maker_asset"))?,
+            taker_asset: self.taker_asset.ok_or(LimitOrderBuilderError::MissingField("taker_asset"))?,
+            making_amount: self.making_amount.ok_or(LimitOrderBuilderError::MissingField("making_amount"))?,
+            taking_amount: self.taking_amount.ok_or(LimitOrderBuilderError::MissingField("taking_amount"))?,
+            maker_traits,
+            extension: encoded_extension,
+        };
+
+        if !order.is_salt_valid() {
+            return Err(LimitOrderBuilderError::InvalidSalt);
+        }
+
+        Ok(order)
+    }
+}
+
+// Generates salt with 96 random high bits and the low bits set to the
+// extension hash.
+fn generate_salt(encoded_extension: &[u8]) -> TokenAmount {
+    let random = BigUint::from(rand::random::<u128>() >> 32);
+    let salt = (random << SALT_EXTENSION_HASH_BITS) | extension_hash_bits(encoded_extension);
+
+    TokenAmount::from_biguint(salt).expect("Salt always fits into 256 bits")
+}
+
+// Low 160 bits of the extension hash, zero for orders without extension.
+fn extension_hash_bits(encoded_extension: &[u8]) -> BigUint {
+    if encoded_extension.is_empty() {
+        return BigUint::default();
+    }
+
+    salt_extension_bits(&BigUint::from_bytes_be(&keccak256(encoded_extension)))
+}
+
+fn salt_extension_bits(value: &BigUint) -> BigUint {
+    value & ((BigUint::from(1u8) << SALT_EXTENSION_HASH_BITS) - 1u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::eip712::typed_data_hash, orderbook::Interaction};
+
+    fn builder() -> LimitOrderBuilder {
+        LimitOrderBuilder::new()
+            .maker(Address::from_bytes([0x13; 20]))
+            .maker_asset(Address::from_bytes([0x55; 20]))
+            .taker_asset(Address::from_bytes([0x1d; 20]))
+            .making_amount(100u64.into())
+            .taking_amount(50u64.into())
+    }
+
+    #[test]
+    fn test_domain() {
+        let domain = limit_order_protocol_domain(SupportedNetworks::BSC);
+        assert_eq!(domain.name, "1inch Aggregation Router");
+        assert_eq!(domain.version, "6");
+        assert_eq!(domain.chain_id, 56);
+        assert_eq!(format!("{:#x}", domain.verifying_contract), "0x111111125421ca6dc452d289314280a0f8842a65");
+        assert_eq!(
+            format!("{:#x}", limit_order_protocol_address(SupportedNetworks::ZkSync)),
+            "0x6fd4383cb451173d5f9304f041c7bcbf27d561ff"
+        );
+    }
+
+    #[test]
+    fn test_order_without_extension() {
+        assert_eq!(
+            LimitOrderBuilder::new().build().err(),
+            Some(LimitOrderBuilderError::MissingField("maker"))
+        );
+
+        let order = builder().salt(42u64.into()).build().unwrap();
+        assert_eq!(order.receiver, Address::ZERO);
+        assert!(!order.maker_traits.has_extension());
+        assert!(order.extension.is_empty());
+
+        let domain = limit_order_protocol_domain(SupportedNetworks::Ethereum);
+        assert_eq!(
+            order.hash(SupportedNetworks::Ethereum),
+            typed_data_hash(&domain.separator(), &order.struct_hash())
+        );
+        assert_ne!(order.hash(SupportedNetworks::Ethereum), order.hash(SupportedNetworks::BSC));
+        assert_eq!(order.hash_hex(SupportedNetworks::Ethereum).len(), 66);
+    }
+
+    #[test]
+    fn test_order_hash_vector() {
+        // Computed with `eip712_signing_hash` of alloy-sol-types for the same
+        // `Order` struct and domain.
+        let order = LimitOrderBuilder::new()
+            .salt(42u64.into())
+            .maker("0x2c7536e3605d9c16a7a3d7b1898e529396a65c23".parse().unwrap())
+            .maker_asset("0xdac17f958d2ee523a2206206994597c13d831ec7".parse().unwrap())
+            .taker_asset("0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2".parse().unwrap())
+            .making_amount(1_000_000u64.into())
+            .taking_amount(1_000_000_000_000_000u64.into())
+            .maker_traits(MakerTraits::default().with_multiple_fills(true))
+            .build()
+            .unwrap();
+
+        let domain = limit_order_protocol_domain(SupportedNetworks::Ethereum);
+        let hex = |hash: [u8; 32]| hash.iter().map(|b| format!("{:02x}", b)).collect::<String>();
+        assert_eq!(
+            hex(domain.separator()),
+            "d999e213f11c7bfa3e796c3409e316f25e02aa3e25e5c207a92e381c7d22b6de"
+        );
+        assert_eq!(
+            hex(order.struct_hash()),
+            "e47d33ccad00bf0a45c192a0f533c4a04d2a134538dc76f924b788d9a03c7fe3"
+        );
+        assert_eq!(
+            order.hash_hex(SupportedNetworks::Ethereum),
+            "0xbe1460aaf85dd362c9ae6934eea892604122e9d9da09ae53de6e6cb2c9811c65"
+        );
+    }
+
+    #[test]
+    fn test_order_with_extension() {
+        let extension = Extension {
+            post_interaction: Interaction::new(Address::from_bytes([0x11; 20]), "0xff".parse().unwrap()).encode(),
+            ..Default::default()
+        };
+
+        let order = builder().extension(extension.clone()).build().unwrap();
+        assert!(order.maker_traits.has_extension());
+        assert!(order.maker_traits.need_post_interaction_call());
+        assert!(!order.maker_traits.need_pre_interaction_call());
+        assert_eq!(Extension::decode(&order.extension).unwrap(), extension);
+        assert!(order.is_salt_valid());
+
+        let hash = BigUint::from_bytes_be(&keccak256(&order.extension));
+        assert_eq!(salt_extension_bits(order.salt.as_biguint()), salt_extension_bits(&hash));
+
+        let error = builder().extension(extension).salt(1u64.into()).build().unwrap_err();
+        assert_eq!(error, LimitOrderBuilderError::InvalidSalt);
+    }
+}
@@ -0,0 +1,316 @@
+use crate::common::{
+    address::Address,
+    amount::{TokenAmount, TokenAmountError},
+};
+use num_bigint::BigUint;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+// Flags stored in the high bits of `makerTraits`.
+const NO_PARTIAL_FILLS_FLAG: u64 = 255;
+const ALLOW_MULTIPLE_FILLS_FLAG: u64 = 254;
+const PRE_INTERACTION_CALL_FLAG: u64 = 252;
+const POST_INTERACTION_CALL_FLAG: u64 = 251;
+const NEED_CHECK_EPOCH_MANAGER_FLAG: u64 = 250;
+const HAS_EXTENSION_FLAG: u64 = 249;
+const USE_PERMIT2_FLAG: u64 = 248;
+const UNWRAP_WETH_FLAG: u64 = 247;
+
+// Fields packed into the low 200 bits of `makerTraits` as (name, offset,
+// width).
+const ALLOWED_SENDER: Field = ("allowed_sender", 0, 80);
+const EXPIRATION: Field = ("expiration", 80, 40);
+const NONCE_OR_EPOCH: Field = ("nonce", 120, 40);
+const SERIES: Field = ("series", 160, 40);
+
+type Field = (&'static str, u64, u64);
+
+/// Enumerates potential errors when setting `MakerTraits` fields.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum MakerTraitsError {
+    /// Indicates the value doesn't fit into the bits of the field.
+    #[error("{field} must fit into {bits} bits")]
+    ValueTooWide { field: &'static str, bits: u64 },
+}
+
+/// `makerTraits` of the Limit Order Protocol v4 order: a `uint256` bitfield
+/// holding order flags, expiration, nonce and allowed sender.
+///
+/// The API passes it as a decimal string, hex strings are accepted as well.
+///
+/// ```
+/// use one_inch::orderbook::MakerTraits;
+///
+/// let traits = MakerTraits::default().with_expiration(1_700_000_000)?.with_nonce(1)?.with_multiple_fills(true);
+/// assert_eq!(traits.expiration(), 1_700_000_000);
+/// assert!(traits.allow_multiple_fills());
+/// # Ok::<(), one_inch::orderbook::MakerTraitsError>(())
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct MakerTraits(BigUint);
+
+impl MakerTraits {
+    /// Creates `MakerTraits` from the raw `uint256` value, returning error if
+    /// it doesn't fit into 256 bits.
+    pub fn from_biguint(value: BigUint) -> Result<Self, TokenAmountError> {
+        TokenAmount::from_biguint(value).map(|value| MakerTraits(value.as_biguint().clone()))
+    }
+
+    /// Returns the raw `uint256` value.
+    pub fn as_biguint(&self) -> &BigUint {
+        &self.0
+    }
+
+    /// Returns the value as big-endian `uint256` word.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let bytes = self.0.to_bytes_be();
+        let mut word = [0u8; 32];
+        // Values are always constructed within 256 bits.
+        word[32 - bytes.len()..].copy_from_slice(&bytes);
+        word
+    }
+
+    /// Returns the low 80 bits of the only address allowed to fill the order.
+    /// Zero means anyone can fill it.
+    pub fn allowed_sender(&self) -> [u8; 10] {
+        let bytes = self.field(ALLOWED_SENDER).to_bytes_be();
+        let mut sender = [0u8; 10];
+        sender[10 - bytes.len()..].copy_from_slice(&bytes);
+        sender
+    }
+
+    /// Returns the order expiration as unix timestamp. Zero means the order
+    /// never expires.
+    pub fn expiration(&self) -> u64 {
+        self.field_u64(EXPIRATION)
+    }
+
+    /// Returns the order nonce, or the epoch when the epoch manager is used.
+    pub fn nonce_or_epoch(&self) -> u64 {
+        self.field_u64(NONCE_OR_EPOCH)
+    }
+
+    /// Returns the epoch manager series of the order.
+    pub fn series(&self) -> u64 {
+        self.field_u64(SERIES)
+    }
+
+    /// Returns true if the order can be filled partially.
+    pub fn allow_partial_fills(&self) -> bool {
+        !self.0.bit(NO_PARTIAL_FILLS_FLAG)
+    }
+
+    /// Returns true if the order can be filled more than once.
+    pub fn allow_multiple_fills(&self) -> bool {
+        self.0.bit(ALLOW_MULTIPLE_FILLS_FLAG)
+    }
+
+    /// Returns true if the maker's pre-interaction must be called.
+    pub fn need_pre_interaction_call(&self) -> bool {
+        self.0.bit(PRE_INTERACTION_CALL_FLAG)
+    }
+
+    /// Returns true if the maker's post-interaction must be called.
+    pub fn need_post_interaction_call(&self) -> bool {
+        self.0.bit(POST_INTERACTION_CALL_FLAG)
+    }
+
+    /// Returns true if the nonce is checked against the epoch manager.
+    pub fn need_check_epoch_manager(&self) -> bool {
+        self.0.bit(NEED_CHECK_EPOCH_MANAGER_FLAG)
+    }
+
+    /// Returns true if the order carries an extension.
+    pub fn has_extension(&self) -> bool {
+        self.0.bit(HAS_EXTENSION_FLAG)
+    }
+
+    /// Returns true if the maker asset is transferred with Permit2.
+    pub fn use_permit2(&self) -> bool {
+        self.0.bit(USE_PERMIT2_FLAG)
+    }
+
+    /// Returns true if WETH received by the maker is unwrapped into ETH.
+    pub fn unwrap_weth(&self) -> bool {
+        self.0.bit(UNWRAP_WETH_FLAG)
+    }
+
+    /// Allows only `sender` to fill the order. Only the low 80 bits of the
+    /// address are stored.
+    pub fn with_allowed_sender(self, sender: Address) -> Self {
+        self.with_field(ALLOWED_SENDER, BigUint::from_bytes_be(&sender.as_bytes()[10..]))
+            .expect("Low 80 bits of the address always fit")
+    }
+
+    /// Sets the order expiration as unix timestamp, zero means no expiration.
+    /// Returns error if the value doesn't fit into 40 bits.
+    pub fn with_expiration(self, expiration: u64) -> Result<Self, MakerTraitsError> {
+        self.with_field(EXPIRATION, expiration.into())
+    }
+
+    /// Sets the order nonce, or the epoch when the epoch manager is used.
+    /// Returns error if the value doesn't fit into 40 bits.
+    pub fn with_nonce(self, nonce: u64) -> Result<Self, MakerTraitsError> {
+        self.with_field(NONCE_OR_EPOCH, nonce.into())
+    }
+
+    /// Sets the epoch manager series of the order. Returns error if the value
+    /// doesn't fit into 40 bits.
+    pub fn with_series(self, series: u64) -> Result<Self, MakerTraitsError> {
+        self.with_field(SERIES, series.into())
+    }
+
+    /// Sets whether the order can be filled partially.
+    pub fn with_partial_fills(self, allow: bool) -> Self {
+        self.with_flag(NO_PARTIAL_FILLS_FLAG, !allow)
+    }
+
+    /// Sets whether the order can be filled more than once.
+    pub fn with_multiple_fills(self, allow: bool) -> Self {
+        self.with_flag(ALLOW_MULTIPLE_FILLS_FLAG, allow)
+    }
+
+    /// Sets whether the maker's pre-interaction must be called.
+    pub fn with_pre_interaction_call(self, value: bool) -> Self {
+        self.with_flag(PRE_INTERACTION_CALL_FLAG, value)
+    }
+
+    /// Sets whether the maker's post-interaction must be called.
+    pub fn with_post_interaction_call(self, value: bool) -> Self {
+        self.with_flag(POST_INTERACTION_CALL_FLAG, value)
+    }
+
+    /// Sets whether the nonce is checked against the epoch manager.
+    pub fn with_epoch_manager_check(self, value: bool) -> Self {
+        self.with_flag(NEED_CHECK_EPOCH_MANAGER_FLAG, value)
+    }
+
+    /// Sets whether the order carries an extension.
+    pub fn with_extension(self, value: bool) -> Self {
+        self.with_flag(HAS_EXTENSION_FLAG, value)
+    }
+
+    /// Sets whether the maker asset is transferred with Permit2.
+    pub fn with_permit2(self, value: bool) -> Self {
+        self.with_flag(USE_PERMIT2_FLAG, value)
+    }
+
+    /// Sets whether WETH received by the maker is unwrapped into ETH.
+    pub fn with_unwrap_weth(self, value: bool) -> Self {
+        self.with_flag(UNWRAP_WETH_FLAG, value)
+    }
+
+    fn with_flag(mut self, bit: u64, value: bool) -> Self {
+        self.0.set_bit(bit, value);
+        self
+    }
+
+    fn with_field(self, (name, offset, width): Field, value: BigUint) -> Result<Self, MakerTraitsError> {
+        if value.bits() > width {
+            return Err(MakerTraitsError::ValueTooWide { field: name, bits: width });
+        }
+
+        let mask = ((BigUint::from(1u8) << width) - 1u8) << offset;
+        let cleared = (&self.0 | &mask) ^ &mask;
+        Ok(MakerTraits(cleared | (value << offset)))
+    }
+
+    fn field(&self, (_, offset, width): Field) -> BigUint {
+        (&self.0 >> offset) & ((BigUint::from(1u8) << width) - 1u8)
+    }
+
+    fn field_u64(&self, field: Field) -> u64 {
+        // All the fields read this way are 40 bits wide, so they always fit.
+        self.field(field).iter_u64_digits().next().unwrap_or(0)
+    }
+}
+
+impl fmt::Debug for MakerTraits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MakerTraits({:#x})", self.0)
+    }
+}
+
+impl fmt::Display for MakerTraits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for MakerTraits {
+    type Err = TokenAmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Reusing `TokenAmount` parsing, as both are `uint256` values.
+        TokenAmount::from_str(s).map(|value| MakerTraits(value.as_biguint().clone()))
+    }
+}
+
+impl Serialize for MakerTraits {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for MakerTraits {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        MakerTraits::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maker_traits_fields() {
+        // allowMultipleFills, hasExtension, expiration 1700000000, nonce 7 and
+        // allowed sender ending with 0xabcd.
+        let raw = (BigUint::from(1u8) << 254u32)
+            | (BigUint::from(1u8) << 249u32)
+            | (BigUint::from(7u8) << 120u32)
+            | (BigUint::from(1_700_000_000u64) << 80u32)
+            | BigUint::from(0xabcdu32);
+        let traits: MakerTraits = format!("0x{:x}", raw).parse().unwrap();
+
+        assert_eq!(traits.expiration(), 1_700_000_000);
+        assert_eq!(traits.nonce_or_epoch(), 7);
+        assert_eq!(traits.series(), 0);
+        assert_eq!(traits.allowed_sender(), [0, 0, 0, 0, 0, 0, 0, 0, 0xab, 0xcd]);
+        assert!(traits.allow_partial_fills());
+        assert!(traits.allow_multiple_fills());
+        assert!(traits.has_extension());
+        assert!(!traits.need_pre_interaction_call());
+        assert!(!traits.use_permit2());
+
+        let built = MakerTraits::default()
+            .with_allowed_sender(Address::from_bytes([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xab, 0xcd]))
+            .with_expiration(1_700_000_000)
+            .unwrap()
+            .with_nonce(7)
+            .unwrap()
+            .with_multiple_fills(true)
+            .with_extension(true);
+        assert_eq!(built, traits);
+        assert!(!built.clone().with_partial_fills(false).allow_partial_fills());
+        assert_eq!(built.clone().with_nonce(8).unwrap().nonce_or_epoch(), 8);
+        assert_eq!(built.to_be_bytes()[..], raw.to_bytes_be()[..]);
+
+        let json = serde_json::to_string(&traits).unwrap();
+        assert_eq!(json, format!("\"{}\"", raw));
+        assert_eq!(serde_json::from_str::<MakerTraits>(&json).unwrap(), traits);
+    }
+
+    #[test]
+    fn test_maker_traits_overflow() {
+        assert_eq!(
+            MakerTraits::default().with_expiration(1 << 40).unwrap_err(),
+            MakerTraitsError::ValueTooWide { field: "expiration", bits: 40 }
+        );
+        assert!(MakerTraits::default().with_nonce(u64::MAX).is_err());
+        assert!(MakerTraits::default().with_series((1 << 40) - 1).is_ok());
+        assert!(MakerTraits::from_biguint(BigUint::from(1u8) << 256u32).is_err());
+    }
+}
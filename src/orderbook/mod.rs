@@ -0,0 +1,11 @@
+mod events;
+mod extension;
+mod limit_order;
+mod maker_traits;
+mod orders;
+mod types;
+
+pub use extension::*;
+pub use limit_order::*;
+pub use maker_traits::*;
+pub use types::*;
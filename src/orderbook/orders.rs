@@ -0,0 +1,139 @@
+use crate::{
+    client::OneInchClient,
+    common::address::Address,
+    consts::ORDERBOOK_API_VERSION,
+    error::OneInchError,
+    orderbook::{
+        HasActiveOrdersWithPermitResponse, OrderByHash, OrderRecord, OrdersCountResponse, OrdersQuery, SubmitOrderRequest,
+        SubmitOrderResponse,
+    },
+    utils::{
+        params::insert_optional_param,
+        request::{parse_url, parse_url_with_params},
+    },
+};
+
+impl OneInchClient {
+    /// Submits signed limit order to the orderbook.
+    pub async fn submit_limit_order(&self, order: SubmitOrderRequest) -> Result<SubmitOrderResponse, OneInchError> {
+        let url = format!("{}/orderbook/{}/{}", self.base_url, ORDERBOOK_API_VERSION, self.network_id);
+
+        self.post_json(parse_url(&url)?, &order).await
+    }
+
+    /// Performs request to get orders of the specified maker.
+    pub async fn get_orders_by_maker(&self, maker: Address, query: OrdersQuery) -> Result<Vec<OrderRecord>, OneInchError> {
+        let url = format!(
+            "{}/orderbook/{}/{}/address/{:#x}",
+            self.base_url, ORDERBOOK_API_VERSION, self.network_id, maker
+        );
+
+        self.get_json(parse_url_with_params(&url, orders_query_params(query, true))?).await
+    }
+
+    /// Performs request to get the order by its hash.
+    pub async fn get_order_by_hash(&self, order_hash: &str) -> Result<OrderByHash, OneInchError> {
+        let url = format!(
+            "{}/orderbook/{}/{}/order/{}",
+            self.base_url, ORDERBOOK_API_VERSION, self.network_id, order_hash
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get all the orders matching the query.
+    pub async fn get_all_orders(&self, query: OrdersQuery) -> Result<Vec<OrderRecord>, OneInchError> {
+        let url = format!("{}/orderbook/{}/{}/all", self.base_url, ORDERBOOK_API_VERSION, self.network_id);
+
+        self.get_json(parse_url_with_params(&url, orders_query_params(query, true))?).await
+    }
+
+    /// Performs request to count the orders matching the query. Pagination and
+    /// sorting of the query are ignored.
+    pub async fn get_orders_count(&self, query: OrdersQuery) -> Result<OrdersCountResponse, OneInchError> {
+        let url = format!("{}/orderbook/{}/{}/count", self.base_url, ORDERBOOK_API_VERSION, self.network_id);
+
+        self.get_json(parse_url_with_params(&url, orders_query_params(query, false))?).await
+    }
+
+    /// Performs request to check whether the wallet has active orders with
+    /// permit for the specified token.
+    pub async fn has_active_orders_with_permit(
+        &self,
+        wallet: Address,
+        token: Address,
+    ) -> Result<HasActiveOrdersWithPermitResponse, OneInchError> {
+        let url = format!(
+            "{}/orderbook/{}/{}/has-active-orders-with-permit/{:#x}/{:#x}",
+            self.base_url, ORDERBOOK_API_VERSION, self.network_id, wallet, token
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+}
+
+// Converts the query into url params, pagination and sorting are included only
+// if `paginated` is true.
+fn orders_query_params(query: OrdersQuery, paginated: bool) -> Vec<(&'static str, String)> {
+    let mut params: Vec<(&str, String)> = vec![];
+
+    if paginated {
+        insert_optional_param(&mut params, "page", query.page.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "limit", query.limit.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "sortBy", query.sort_by.map(|a| a.to_string()));
+    }
+
+    if !query.statuses.is_empty() {
+        let statuses = query.statuses.iter().map(|s| u8::from(*s).to_string()).collect::<Vec<String>>().join(",");
+        params.push(("statuses", statuses));
+    }
+
+    insert_optional_param(&mut params, "takerAsset", query.taker_asset.map(|a| format!("{:#x}", a)));
+    insert_optional_param(&mut params, "makerAsset", query.maker_asset.map(|a| format!("{:#x}", a)));
+
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::bytes::Bytes,
+        orderbook::{LimitOrder, MakerTraits, OrderStatus},
+        transport::{testing::*, Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_orderbook_with_mock() {
+        let transport = MockTransport::new();
+        transport
+            .register(Mock::post("/orderbook/v4.0/56").json_body(r#"{"success":true}"#))
+            .register(Mock::get("/orderbook/v4.0/56/count").query_param("statuses", "1,2").json_body(r#"{"count":3}"#));
+
+        let client = client_with(&transport);
+
+        let order = LimitOrder {
+            salt: 1u64.into(),
+            maker: WALLET.parse().unwrap(),
+            receiver: Address::ZERO,
+            maker_asset: USDT.parse().unwrap(),
+            taker_asset: XRP.parse().unwrap(),
+            making_amount: 100u64.into(),
+            taking_amount: 50u64.into(),
+            maker_traits: MakerTraits::default(),
+            extension: Bytes::new(),
+        };
+        let request = SubmitOrderRequest { order_hash: "0xabc".into(), signature: "0x1234".parse().unwrap(), data: order };
+        assert!(client.submit_limit_order(request).await.unwrap().success);
+
+        let query = OrdersQuery { statuses: vec![OrderStatus::Valid, OrderStatus::TemporarilyInvalid], ..Default::default() };
+        assert_eq!(client.get_orders_count(query).await.unwrap().count, 3);
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].headers.get("Content-Type").unwrap(), "application/json");
+        let body: serde_json::Value = serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+        assert_eq!(body["data"]["makingAmount"], "100");
+        assert_eq!(body["data"]["makerTraits"], "0");
+        assert_eq!(body["signature"], "0x1234");
+    }
+}
@@ -0,0 +1,324 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    common::{address::Address, amount::TokenAmount, bytes::Bytes},
+    orderbook::MakerTraits,
+    signer::Signature,
+};
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use thiserror::Error;
+
+/// Limit order of the 1inch Limit Order Protocol v4, in the form it's
+/// submitted to and returned by the orderbook API.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LimitOrder {
+    pub salt: TokenAmount,
+    pub maker: Address,
+
+    /// Address receiving the taker asset. Zero address means the maker.
+    pub receiver: Address,
+    pub maker_asset: Address,
+    pub taker_asset: Address,
+    pub making_amount: TokenAmount,
+    pub taking_amount: TokenAmount,
+    pub maker_traits: MakerTraits,
+
+    /// Encoded [`Extension`](crate::orderbook::Extension) of the order, empty
+    /// if the order has no extension.
+    #[serde(default)]
+    pub extension: Bytes,
+}
+
+/// Signed order, submitted to the orderbook with
+/// [`submit_limit_order`](crate::client::OneInchClient::submit_limit_order).
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitOrderRequest {
+    pub order_hash: String,
+    pub signature: Bytes,
+    pub data: LimitOrder,
+}
+
+impl SubmitOrderRequest {
+    /// Creates request for the order signed by the maker in the network.
+    pub fn new(order: LimitOrder, signature: &Signature, network: SupportedNetworks) -> Self {
+        SubmitOrderRequest { order_hash: order.hash_hex(network), signature: (*signature).into(), data: order }
+    }
+}
+
+/// Response received after the order is submitted.
+#[derive(Deserialize, Debug)]
+pub struct SubmitOrderResponse {
+    pub success: bool,
+}
+
+/// Status of the order in the orderbook.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum OrderStatus {
+    /// Order can be filled.
+    Valid = 1,
+
+    /// Order can't be filled right now, e.g. the maker has not enough balance
+    /// or allowance.
+    TemporarilyInvalid = 2,
+
+    /// Order is expired, cancelled or filled.
+    Invalid = 3,
+}
+
+impl TryFrom<u8> for OrderStatus {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(OrderStatus::Valid),
+            2 => Ok(OrderStatus::TemporarilyInvalid),
+            3 => Ok(OrderStatus::Invalid),
+            _ => Err(format!("Unknown order status {}", value)),
+        }
+    }
+}
+
+impl From<OrderStatus> for u8 {
+    fn from(status: OrderStatus) -> Self {
+        status as u8
+    }
+}
+
+/// Field the orders are sorted by.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[strum(serialize_all = "camelCase")]
+pub enum OrderSortBy {
+    CreateDateTime,
+    TakerRate,
+    MakerRate,
+    MakerAmount,
+    TakerAmount,
+}
+
+/// Filters and pagination for the orders listing endpoints. The default
+/// value performs request without any filters.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersQuery {
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub statuses: Vec<OrderStatus>,
+    pub sort_by: Option<OrderSortBy>,
+    pub taker_asset: Option<Address>,
+    pub maker_asset: Option<Address>,
+}
+
+/// Enumerates potential errors when building `OrdersQuery`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum OrdersQueryBuilderError {
+    /// Indicates the page is zero, pages start from 1.
+    #[error("Page must be greater than 0")]
+    InvalidPage,
+
+    /// Indicates the limit is out of the allowed range.
+    #[error("Limit must be between 1 and 500")]
+    InvalidLimit,
+}
+
+/// Builder to create instance of
+/// [`OrdersQuery`](crate::orderbook::OrdersQuery).
+#[derive(Default)]
+pub struct OrdersQueryBuilder {
+    page: Option<u32>,
+    limit: Option<u32>,
+    statuses: Option<Vec<OrderStatus>>,
+    sort_by: Option<OrderSortBy>,
+    taker_asset: Option<Address>,
+    maker_asset: Option<Address>,
+}
+
+impl OrdersQueryBuilder {
+    pub fn new() -> Self {
+        OrdersQueryBuilder::default()
+    }
+
+    builder_setter!(statuses, Vec<OrderStatus>);
+    builder_setter!(sort_by, OrderSortBy);
+    builder_setter!(taker_asset, Address);
+    builder_setter!(maker_asset, Address);
+
+    /// Special setter for page that ensures value is within allowable range.
+    pub fn page(mut self, page: u32) -> Result<Self, OrdersQueryBuilderError> {
+        if page == 0 {
+            return Err(OrdersQueryBuilderError::InvalidPage);
+        }
+        self.page = Some(page);
+        Ok(self)
+    }
+
+    /// Special setter for limit that ensures value is within allowable range.
+    pub fn limit(mut self, limit: u32) -> Result<Self, OrdersQueryBuilderError> {
+        if !(1..=500).contains(&limit) {
+            return Err(OrdersQueryBuilderError::InvalidLimit);
+        }
+        self.limit = Some(limit);
+        Ok(self)
+    }
+
+    /// Constructs [`OrdersQuery`](crate::orderbook::OrdersQuery) from the
+    /// builder.
+    pub fn build(self) -> OrdersQuery {
+        OrdersQuery {
+            page: self.page,
+            limit: self.limit,
+            statuses: self.statuses.unwrap_or_default(),
+            sort_by: self.sort_by,
+            taker_asset: self.taker_asset,
+            maker_asset: self.maker_asset,
+        }
+    }
+}
+
+/// Order returned by the orders listing endpoints.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRecord {
+    pub signature: Bytes,
+    pub order_hash: String,
+    pub create_date_time: String,
+    pub remaining_maker_amount: TokenAmount,
+    pub maker_balance: TokenAmount,
+    pub maker_allowance: TokenAmount,
+    pub data: LimitOrder,
+    pub maker_rate: String,
+    pub taker_rate: String,
+    pub is_maker_contract: bool,
+    pub order_invalid_reason: Option<String>,
+}
+
+/// Order returned by
+/// [`get_order_by_hash`](crate::client::OneInchClient::get_order_by_hash).
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderByHash {
+    pub id: u64,
+    pub order_hash: String,
+    pub create_date_time: String,
+    pub last_changed_date_time: String,
+    pub taker_asset: Address,
+    pub maker_asset: Address,
+    pub order_status: OrderStatus,
+    pub maker_amount: TokenAmount,
+    pub remaining_maker_amount: TokenAmount,
+    pub maker_balance: TokenAmount,
+    pub maker_allowance: TokenAmount,
+    pub taker_amount: TokenAmount,
+    pub data: LimitOrder,
+    pub maker_rate: String,
+    pub taker_rate: String,
+    pub order_invalid_reason: Option<String>,
+    pub is_maker_contract: bool,
+    pub signature: Bytes,
+}
+
+/// Number of orders matching the filters.
+#[derive(Deserialize, Debug)]
+pub struct OrdersCountResponse {
+    pub count: u64,
+}
+
+/// Kind of the order event.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderEventAction {
+    Fill,
+    Cancel,
+
+    /// Any action not known to this crate yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Fill or cancel event of the order.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderEvent {
+    pub id: u64,
+    pub network: u32,
+    pub log_id: String,
+    pub version: u32,
+    pub action: OrderEventAction,
+    pub order_hash: String,
+    pub taker: Address,
+    pub remaining_maker_amount: TokenAmount,
+    pub transaction_hash: String,
+    pub block_number: u64,
+    pub create_date_time: String,
+}
+
+/// Response for the "has active orders with permit" lookup.
+#[derive(Deserialize, Debug)]
+pub struct HasActiveOrdersWithPermitResponse {
+    pub result: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orders_query_builder() {
+        assert_eq!(OrdersQueryBuilder::new().page(0).err(), Some(OrdersQueryBuilderError::InvalidPage));
+        assert_eq!(OrdersQueryBuilder::new().limit(501).err(), Some(OrdersQueryBuilderError::InvalidLimit));
+
+        let query = OrdersQueryBuilder::new()
+            .page(2)
+            .unwrap()
+            .limit(100)
+            .unwrap()
+            .statuses(vec![OrderStatus::Valid, OrderStatus::TemporarilyInvalid])
+            .sort_by(OrderSortBy::CreateDateTime)
+            .build();
+        assert_eq!(query.page, Some(2));
+        assert_eq!(query.statuses.len(), 2);
+        assert_eq!(query.sort_by.unwrap().to_string(), "createDateTime");
+    }
+
+    #[test]
+    fn test_order_by_hash_deserialization() {
+        let json = r#"{
+            "id": 1,
+            "orderHash": "0xabc",
+            "createDateTime": "2024-01-01T00:00:00.000Z",
+            "lastChangedDateTime": "2024-01-01T00:00:00.000Z",
+            "takerAsset": "0x55d398326f99059ff775485246999027b3197955",
+            "makerAsset": "0x1d2f0da169ceb9fc7b3144628db156f3f6c60dbe",
+            "orderStatus": 2,
+            "makerAmount": "100",
+            "remainingMakerAmount": "100",
+            "makerBalance": "0",
+            "makerAllowance": "0",
+            "takerAmount": "50",
+            "data": {
+                "salt": "1",
+                "maker": "0x13961a09bcd42dcc078765286be746d87f20e82e",
+                "receiver": "0x0000000000000000000000000000000000000000",
+                "makerAsset": "0x1d2f0da169ceb9fc7b3144628db156f3f6c60dbe",
+                "takerAsset": "0x55d398326f99059ff775485246999027b3197955",
+                "makingAmount": "100",
+                "takingAmount": "50",
+                "makerTraits": "0x4000000000000000000000000000000000000000000000000000000000000000",
+                "extension": "0x"
+            },
+            "makerRate": "0.5",
+            "takerRate": "2",
+            "orderInvalidReason": null,
+            "isMakerContract": false,
+            "signature": "0x1234"
+        }"#;
+
+        let order: OrderByHash = serde_json::from_str(json).unwrap();
+        assert_eq!(order.order_status, OrderStatus::TemporarilyInvalid);
+        assert!(order.data.maker_traits.allow_multiple_fills());
+        assert!(order.data.extension.is_empty());
+        assert_eq!(order.data.receiver, Address::ZERO);
+    }
+}
@@ -0,0 +1,4 @@
+mod overview;
+mod types;
+
+pub use types::*;
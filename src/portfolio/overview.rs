@@ -0,0 +1,91 @@
+use crate::{
+    client::OneInchClient,
+    consts::PORTFOLIO_API_VERSION,
+    error::OneInchError,
+    portfolio::{
+        CurrentValue, PortfolioAssets, PortfolioQuery, PortfolioResponse, ProfitAndLoss, ProtocolDetails, TokenDetails,
+        ValueChartPoint,
+    },
+    utils::{params::insert_optional_param, request::parse_url_with_params},
+};
+
+impl OneInchClient {
+    /// Performs request to get current value of the assets of the wallets.
+    pub async fn get_portfolio_current_value(
+        &self,
+        assets: PortfolioAssets,
+        query: PortfolioQuery,
+    ) -> Result<CurrentValue, OneInchError> {
+        self.get_portfolio(&format!("overview/{}/current_value", assets), query).await
+    }
+
+    /// Performs request to get profit and loss of the assets of the wallets
+    /// over the query time range.
+    pub async fn get_portfolio_profit_and_loss(
+        &self,
+        assets: PortfolioAssets,
+        query: PortfolioQuery,
+    ) -> Result<Vec<ProfitAndLoss>, OneInchError> {
+        self.get_portfolio(&format!("overview/{}/profit_and_loss", assets), query).await
+    }
+
+    /// Performs request to get tokens of the wallets with their values.
+    pub async fn get_portfolio_tokens_details(&self, query: PortfolioQuery) -> Result<Vec<TokenDetails>, OneInchError> {
+        self.get_portfolio(&format!("overview/{}/details", PortfolioAssets::Erc20), query).await
+    }
+
+    /// Performs request to get protocol positions of the wallets with their
+    /// values.
+    pub async fn get_portfolio_protocols_details(&self, query: PortfolioQuery) -> Result<Vec<ProtocolDetails>, OneInchError> {
+        self.get_portfolio(&format!("overview/{}/details", PortfolioAssets::Protocols), query).await
+    }
+
+    /// Performs request to get value of the wallets over the query time range.
+    pub async fn get_portfolio_value_chart(&self, query: PortfolioQuery) -> Result<Vec<ValueChartPoint>, OneInchError> {
+        self.get_portfolio("general/value_chart", query).await
+    }
+
+    // Portfolio isn't bound to the client network, the network is a filter of
+    // the query instead.
+    async fn get_portfolio<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        query: PortfolioQuery,
+    ) -> Result<T, OneInchError> {
+        let url = format!("{}/portfolio/portfolio/{}/portfolio/{}", self.base_url, PORTFOLIO_API_VERSION, endpoint);
+
+        let mut params: Vec<(&str, String)> =
+            query.addresses.iter().map(|address| ("addresses", format!("{:#x}", address))).collect();
+        insert_optional_param(&mut params, "chain_id", query.chain.map(|c| c.to_string()));
+        insert_optional_param(&mut params, "timerange", query.timerange.map(|t| t.to_string()));
+
+        let response: PortfolioResponse<T> = self.get_json(parse_url_with_params(&url, params)?).await?;
+        Ok(response.result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::{PortfolioQueryBuilder, TimeRange},
+        transport::{testing::*, Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_portfolio_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(
+            Mock::get("/portfolio/portfolio/v3/portfolio/overview/erc20/profit_and_loss")
+                .query_param("addresses", WALLET)
+                .query_param("timerange", "1week")
+                .json_body(r#"{"result":[{"chain_id":null,"abs_profit_usd":12.5,"roi":0.1}]}"#),
+        );
+
+        let query =
+            PortfolioQueryBuilder::new().addresses(vec![WALLET.parse().unwrap()]).timerange(TimeRange::OneWeek).build().unwrap();
+        let pnl = client_with(&transport).get_portfolio_profit_and_loss(PortfolioAssets::Erc20, query).await.unwrap();
+        assert_eq!(pnl[0].chain_id, None);
+        assert_eq!(pnl[0].abs_profit_usd, 12.5);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{builder_setter, client::SupportedNetworks, common::address::Address, utils::builder::BasicBuilderError};
+use serde::Deserialize;
+use strum_macros::Display;
+
+/// Assets the portfolio overview is requested for.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+pub enum PortfolioAssets {
+    /// Tokens held by the wallets.
+    Erc20,
+
+    /// Positions in DeFi protocols, e.g. liquidity pools and lending.
+    Protocols,
+}
+
+/// Period the profit and loss or the value chart are computed for.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TimeRange {
+    #[strum(serialize = "1day")]
+    OneDay,
+    #[strum(serialize = "1week")]
+    OneWeek,
+    #[strum(serialize = "1month")]
+    OneMonth,
+    #[strum(serialize = "1year")]
+    OneYear,
+    #[strum(serialize = "3years")]
+    ThreeYears,
+}
+
+/// Wallets and filters of the portfolio request.
+#[derive(Debug, Clone)]
+pub struct PortfolioQuery {
+    pub addresses: Vec<Address>,
+
+    /// Network to limit the portfolio to, all the networks if `None`.
+    pub chain: Option<SupportedNetworks>,
+    pub timerange: Option<TimeRange>,
+}
+
+/// Builder to create instance of
+/// [`PortfolioQuery`](crate::portfolio::PortfolioQuery).
+#[derive(Default)]
+pub struct PortfolioQueryBuilder {
+    addresses: Option<Vec<Address>>,
+    chain: Option<SupportedNetworks>,
+    timerange: Option<TimeRange>,
+}
+
+impl PortfolioQueryBuilder {
+    pub fn new() -> Self {
+        PortfolioQueryBuilder::default()
+    }
+
+    builder_setter!(addresses, Vec<Address>);
+    builder_setter!(chain, SupportedNetworks);
+    builder_setter!(timerange, TimeRange);
+
+    /// Attempts to construct a
+    /// [`PortfolioQuery`](crate::portfolio::PortfolioQuery) from the builder,
+    /// returning error if no addresses are provided.
+    pub fn build(self) -> Result<PortfolioQuery, BasicBuilderError> {
+        Ok(PortfolioQuery {
+            addresses: self.addresses.filter(|a| !a.is_empty()).ok_or(BasicBuilderError::MissingField("addresses"))?,
+            chain: self.chain,
+            timerange: self.timerange,
+        })
+    }
+}
+
+// Portfolio API wraps all the responses into `result`.
+#[derive(Deserialize, Debug)]
+pub(crate) struct PortfolioResponse<T> {
+    pub result: T,
+}
+
+/// Value of the wallet.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AddressValue {
+    pub address: Address,
+    pub value_usd: f64,
+}
+
+/// Value of the assets in the network.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChainValue {
+    pub chain_id: u32,
+    pub chain_name: String,
+    pub value_usd: f64,
+}
+
+/// Value of the assets of the category, e.g. tokens or lending.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CategoryValue {
+    pub category_id: String,
+    pub category_name: String,
+    pub value_usd: f64,
+}
+
+/// Value of the positions in the protocol group, e.g. all Uniswap versions.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProtocolGroupValue {
+    pub protocol_group_id: String,
+    pub protocol_group_name: String,
+    pub value_usd: f64,
+}
+
+/// Current value of the portfolio and its breakdowns.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CurrentValue {
+    pub total: f64,
+
+    #[serde(default)]
+    pub by_address: Vec<AddressValue>,
+
+    #[serde(default)]
+    pub by_category: Vec<CategoryValue>,
+
+    #[serde(default)]
+    pub by_protocol_group: Vec<ProtocolGroupValue>,
+
+    #[serde(default)]
+    pub by_chain: Vec<ChainValue>,
+}
+
+/// Profit and loss of the portfolio in the network, or in all the networks if
+/// `chain_id` is `None`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProfitAndLoss {
+    pub chain_id: Option<u32>,
+    pub abs_profit_usd: f64,
+
+    /// Return on investment, `0.1` meaning 10%.
+    pub roi: f64,
+}
+
+/// Token held by the wallets.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TokenDetails {
+    pub chain_id: u32,
+    pub address: Address,
+    pub contract_address: Address,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub amount: f64,
+    pub price_to_usd: f64,
+    pub value_usd: f64,
+    pub abs_profit_usd: Option<f64>,
+    pub roi: Option<f64>,
+}
+
+/// Token underlying the protocol position.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UnderlyingToken {
+    pub chain_id: u32,
+    pub address: Address,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub amount: f64,
+    pub price_to_usd: f64,
+    pub value_usd: f64,
+}
+
+/// Position of the wallet in the protocol.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProtocolDetails {
+    pub chain_id: u32,
+    pub address: Address,
+    pub contract_address: Address,
+    pub protocol: String,
+    pub name: Option<String>,
+    pub value_usd: f64,
+
+    #[serde(default)]
+    pub underlying_tokens: Vec<UnderlyingToken>,
+
+    #[serde(default)]
+    pub reward_tokens: Vec<UnderlyingToken>,
+    pub abs_profit_usd: Option<f64>,
+    pub roi: Option<f64>,
+}
+
+/// Value of the portfolio at the moment.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ValueChartPoint {
+    pub timestamp: u64,
+    pub value_usd: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_value_deserialization() {
+        let json = r#"{"result": {
+            "total": 1500.5,
+            "by_address": [{"address": "0x13961a09bcd42dcc078765286be746d87f20e82e", "value_usd": 1500.5}],
+            "by_category": [{"category_id": "tokens", "category_name": "Tokens", "value_usd": 1000}],
+            "by_protocol_group": [{"protocol_group_id": "uniswap", "protocol_group_name": "Uniswap", "value_usd": 500.5}],
+            "by_chain": [{"chain_id": 56, "chain_name": "BNB Chain", "value_usd": 1500.5}]
+        }}"#;
+
+        let value = serde_json::from_str::<PortfolioResponse<CurrentValue>>(json).unwrap().result;
+        assert_eq!(value.total, 1500.5);
+        assert_eq!(value.by_chain[0].chain_id, 56);
+        assert_eq!(value.by_protocol_group[0].protocol_group_name, "Uniswap");
+    }
+
+    #[test]
+    fn test_portfolio_query_builder() {
+        assert_eq!(
+            PortfolioQueryBuilder::new().build().err(),
+            Some(BasicBuilderError::MissingField("addresses"))
+        );
+        assert!(PortfolioQueryBuilder::new().addresses(vec![]).build().is_err());
+        assert_eq!(TimeRange::ThreeYears.to_string(), "3years");
+        assert_eq!(PortfolioAssets::Erc20.to_string(), "erc20");
+    }
+}
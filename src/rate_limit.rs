@@ -0,0 +1,119 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// Client-side token bucket limiter used to stay under the requests-per-second
+/// quota of the 1inch API key.
+///
+/// The limiter is cheap to clone and all the clones share the same bucket, so
+/// one limiter can coordinate any amount of concurrent tasks and clients
+/// using the same API key. Every request sent by
+/// [`OneInchClient`](crate::client::OneInchClient), including retries, takes a
+/// token from the bucket first.
+///
+/// ```
+/// use one_inch::rate_limit::RateLimiter;
+///
+/// // 1inch dev plan allows 1 request per second.
+/// let limiter = RateLimiter::new(1.0, 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    requests_per_second: f64,
+    burst: f64,
+
+    // Amount of available tokens. Becomes negative when there are callers
+    // waiting for their turn.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `requests_per_second` requests on average,
+    /// with up to `burst` requests sent at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_second` is not a positive number or `burst` is
+    /// zero.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        assert!(
+            requests_per_second.is_finite() && requests_per_second > 0.0,
+            "requests_per_second must be positive"
+        );
+        assert!(burst > 0, "burst must be positive");
+
+        let bucket = TokenBucket { requests_per_second, burst: burst as f64, tokens: burst as f64, last_refill: Instant::now() };
+
+        RateLimiter { bucket: Arc::new(Mutex::new(bucket)) }
+    }
+
+    /// Waits until a request is allowed to be sent.
+    pub async fn acquire(&self) {
+        let wait = self.reserve(Instant::now());
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    // Takes a token from the bucket and returns how long the caller must wait
+    // before using it.
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+
+        let elapsed = now.saturating_duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * bucket.requests_per_second).min(bucket.burst);
+        bucket.last_refill = now;
+
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / bucket.requests_per_second)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_millis(actual: Duration, expected: u64) {
+        assert!(
+            (actual.as_secs_f64() - expected as f64 / 1000.0).abs() < 1e-6,
+            "{:?} != {}ms",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_reserve_respects_burst_and_rate() {
+        let limiter = RateLimiter::new(10.0, 2);
+        let now = Instant::now();
+
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_millis(limiter.reserve(now), 100);
+        assert_millis(limiter.reserve(now), 200);
+
+        // Clones share the same bucket
+        let cloned = limiter.clone();
+        assert_eq!(cloned.reserve(now + Duration::from_millis(300)), Duration::ZERO);
+        assert_millis(limiter.reserve(now + Duration::from_millis(300)), 100);
+
+        // Bucket is refilled up to burst only
+        assert_eq!(limiter.reserve(now + Duration::from_secs(10)), Duration::ZERO);
+        assert_eq!(limiter.reserve(now + Duration::from_secs(10)), Duration::ZERO);
+        assert_millis(limiter.reserve(now + Duration::from_secs(10)), 100);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::error::OneInchError;
+use rand::Rng;
+use std::time::Duration;
+
+/// Describes how the client retries requests that failed with a transient
+/// error, like `429 Too Many Requests` or `503 Service Unavailable`.
+///
+/// Retries are disabled by default, use
+/// [`OneInchClientBuilder::retry_policy`](crate::client::OneInchClientBuilder::retry_policy)
+/// to enable them. Only idempotent requests (like all the GET endpoints) are
+/// retried unless `retry_non_idempotent` is set.
+///
+/// ```
+/// use one_inch::retry::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy { max_attempts: 5, initial_backoff: Duration::from_millis(200), ..Default::default() };
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Total amount of attempts, including the first one.
+    pub max_attempts: u32,
+
+    /// Delay before the first retry.
+    pub initial_backoff: Duration,
+
+    /// Upper bound for the delay computed from the backoff curve.
+    pub max_backoff: Duration,
+
+    /// Factor the delay is multiplied by after each failed attempt.
+    pub backoff_multiplier: f64,
+
+    /// Randomization applied to the computed delay.
+    pub jitter: Jitter,
+
+    /// Http status codes which are considered transient.
+    pub retryable_statuses: Vec<u16>,
+
+    /// Whether connection errors and timeouts should be retried.
+    pub retry_network_errors: bool,
+
+    /// Whether the delay requested by the server in `Retry-After` header
+    /// should be respected. The longest of it and computed backoff is used.
+    pub respect_retry_after: bool,
+
+    /// Whether non-idempotent requests (e.g. POST) should be retried too.
+    pub retry_non_idempotent: bool,
+}
+
+/// Randomization strategies for the backoff delay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    /// Delay is used as is.
+    None,
+
+    /// Delay is picked uniformly between zero and the computed value.
+    Full,
+
+    /// Delay is picked uniformly between a half of the computed value and the
+    /// value itself.
+    Equal,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            backoff_multiplier: 2.0,
+            jitter: Jitter::Equal,
+            retryable_statuses: vec![429, 500, 502, 503, 504],
+            retry_network_errors: true,
+            respect_retry_after: true,
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns whether the request which failed with `error` on attempt number
+    /// `attempt` (starting from 1) should be retried.
+    pub fn should_retry(&self, error: &OneInchError, attempt: u32, idempotent: bool) -> bool {
+        if attempt >= self.max_attempts || !(idempotent || self.retry_non_idempotent) {
+            return false;
+        }
+
+        match error {
+            OneInchError::Network(e) => self.retry_network_errors && (e.is_timeout() || e.is_connect()),
+            OneInchError::RateLimited { .. } => self.retryable_statuses.contains(&429),
+            OneInchError::Http { status, .. } => self.retryable_statuses.contains(status),
+            _ => false,
+        }
+    }
+
+    /// Returns the backoff delay after attempt number `attempt` (starting from
+    /// 1), without jitter applied.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_backoff.as_secs_f64() * self.backoff_multiplier.powi(exponent);
+
+        if delay.is_finite() && delay < self.max_backoff.as_secs_f64() {
+            Duration::from_secs_f64(delay)
+        } else {
+            self.max_backoff
+        }
+    }
+
+    // Computes the delay before the next attempt.
+    pub(crate) fn delay(&self, error: &OneInchError, attempt: u32) -> Duration {
+        let backoff = self.backoff(attempt);
+
+        let backoff = match self.jitter {
+            Jitter::None => backoff,
+            Jitter::Full => backoff.mul_f64(rand::thread_rng().gen_range(0.0..=1.0)),
+            Jitter::Equal => backoff.mul_f64(rand::thread_rng().gen_range(0.5..=1.0)),
+        };
+
+        match error {
+            OneInchError::RateLimited { retry_after: Some(retry_after) } if self.respect_retry_after => backoff.max(*retry_after),
+            _ => backoff,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{OneInchClient, SupportedNetworks},
+        transport::{Mock, MockTransport},
+    };
+
+    #[test]
+    fn test_backoff_curve() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(1000),
+            jitter: Jitter::None,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(5), Duration::from_millis(1000));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy { max_attempts: 3, ..Default::default() };
+
+        let rate_limited = OneInchError::RateLimited { retry_after: Some(Duration::from_secs(5)) };
+        let bad_request = OneInchError::Http { status: 400, api_error: None, body: String::new() };
+        let unavailable = OneInchError::Http { status: 503, api_error: None, body: String::new() };
+
+        assert!(policy.should_retry(&rate_limited, 1, true));
+        assert!(policy.should_retry(&unavailable, 2, true));
+        assert!(!policy.should_retry(&unavailable, 3, true));
+        assert!(!policy.should_retry(&unavailable, 1, false));
+        assert!(!policy.should_retry(&bad_request, 1, true));
+
+        assert!(policy.delay(&rate_limited, 1) >= Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_mock() {
+        let transport = MockTransport::new();
+        transport
+            .register(Mock::get("/swap/v5.2/56/tokens").status(429).header("Retry-After", "0").times(2))
+            .register(Mock::get("/swap/v5.2/56/tokens").json_body(r#"{"tokens":{}}"#));
+
+        let policy = RetryPolicy { initial_backoff: Duration::ZERO, jitter: Jitter::None, ..Default::default() };
+        let client = OneInchClient::builder()
+            .token("test-token".to_string())
+            .network_id(SupportedNetworks::BSC)
+            .transport(transport.clone())
+            .retry_policy(policy)
+            .build()
+            .unwrap();
+
+        let tokens = client.get_tokens_list().await.unwrap();
+        assert!(tokens.tokens.is_empty());
+        assert_eq!(transport.requests().len(), 3);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    common::{address::Address, bytes::Bytes},
+    signer::{address_from_verifying_key, Signature, Signer, SignerError},
+};
+use k256::ecdsa::SigningKey;
+use std::{fmt, str::FromStr};
+
+/// Signer holding secp256k1 private key in memory.
+///
+/// ```
+/// use one_inch::signer::{LocalSigner, Signer};
+///
+/// let signer: LocalSigner = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318".parse().unwrap();
+/// assert_eq!(signer.address().to_string(), "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23");
+///
+/// let signature = signer.sign_message(b"Some data").unwrap();
+/// ```
+#[derive(Clone)]
+pub struct LocalSigner {
+    key: SigningKey,
+    address: Address,
+}
+
+impl LocalSigner {
+    /// Creates signer from 32-byte private key.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, SignerError> {
+        let key = SigningKey::from_slice(bytes).map_err(|_| SignerError::InvalidPrivateKey)?;
+        let address = address_from_verifying_key(key.verifying_key());
+
+        Ok(LocalSigner { key, address })
+    }
+
+    /// Creates signer with a new random private key.
+    pub fn random() -> Self {
+        loop {
+            // Almost all 32-byte values are valid keys, so this loop ends after
+            // the first iteration in practice.
+            if let Ok(signer) = LocalSigner::from_bytes(&rand::random()) {
+                return signer;
+            }
+        }
+    }
+
+    /// Returns the private key bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.key.to_bytes().into()
+    }
+}
+
+impl Signer for LocalSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn sign_hash(&self, hash: &[u8; 32]) -> Result<Signature, SignerError> {
+        let (signature, recovery_id) =
+            self.key.sign_prehash_recoverable(hash).map_err(|e| SignerError::Signing(e.to_string()))?;
+
+        // Ethereum only accepts signatures with low `s`.
+        let (signature, y_parity) = match signature.normalize_s() {
+            Some(normalized) => (normalized, !recovery_id.is_y_odd()),
+            None => (signature, recovery_id.is_y_odd()),
+        };
+
+        let (r, s) = signature.split_bytes();
+        Ok(Signature { r: r.into(), s: s.into(), y_parity })
+    }
+}
+
+impl FromStr for LocalSigner {
+    type Err = SignerError;
+
+    /// Parses hex encoded private key, with or without `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.parse::<Bytes>().map_err(|_| SignerError::InvalidPrivateKey)?;
+        let bytes = <[u8; 32]>::try_from(bytes.as_ref()).map_err(|_| SignerError::InvalidPrivateKey)?;
+
+        LocalSigner::from_bytes(&bytes)
+    }
+}
+
+// Private key must never end up in logs.
+impl fmt::Debug for LocalSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSigner").field("address", &self.address).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::SupportedNetworks,
+        common::eip712::{Eip712, Eip712Domain},
+        orderbook::{LimitOrderBuilder, SubmitOrderRequest},
+        signer::{hash_message, Eip2612Permit},
+        utils::keccak::keccak256,
+    };
+
+    const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn test_address_derivation() {
+        let signer: LocalSigner = PRIVATE_KEY.parse().unwrap();
+        assert_eq!(
+            signer.address(),
+            Address::parse_checksummed("0x2c7536E3605D9C16a7a3D7b1898e529396a65c23").unwrap()
+        );
+        assert_eq!(LocalSigner::from_bytes(&signer.to_bytes()).unwrap().address(), signer.address());
+        assert!(!format!("{:?}", signer).contains("4c0883"));
+
+        assert_eq!("0x1234".parse::<LocalSigner>().err(), Some(SignerError::InvalidPrivateKey));
+        // 64 bytes long, but not 64 characters.
+        let multibyte = format!("{}é{}", "0".repeat(31), "0".repeat(31));
+        assert_eq!(multibyte.parse::<LocalSigner>().err(), Some(SignerError::InvalidPrivateKey));
+        let signed = format!("+f{}", &PRIVATE_KEY[4..]);
+        assert_eq!(signed.parse::<LocalSigner>().err(), Some(SignerError::InvalidPrivateKey));
+        assert_eq!(LocalSigner::from_bytes(&[0u8; 32]).err(), Some(SignerError::InvalidPrivateKey));
+    }
+
+    #[test]
+    fn test_sign_message() {
+        // Example from the web3.js `accounts.sign` documentation.
+        let signer: LocalSigner = PRIVATE_KEY.parse().unwrap();
+        let signature = signer.sign_message(b"Some data").unwrap();
+
+        assert_eq!(
+            signature.to_string(),
+            "0xb91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a0291c"
+        );
+        assert_eq!(signature.recover(&hash_message(b"Some data")).unwrap(), signer.address());
+        assert_eq!(signature.to_string().parse::<Signature>().unwrap(), signature);
+
+        let malformed = signature.to_string().replacen("0xb9", "0x+9", 1);
+        assert_eq!(malformed.parse::<Signature>().err(), Some(SignerError::InvalidSignature));
+    }
+
+    #[test]
+    fn test_sign_typed_data() {
+        let signer = LocalSigner::random();
+        let domain = Eip712Domain::new("USD Coin", "2", 1, Address::from_bytes([0xa0; 20]));
+        let permit = Eip2612Permit {
+            owner: signer.address(),
+            spender: Address::from_bytes([0x11; 20]),
+            value: 1000u64.into(),
+            nonce: 0u64.into(),
+            deadline: 1_700_000_000,
+        };
+
+        let signature = signer.sign_typed_data(&permit, &domain).unwrap();
+        assert_eq!(signature, signer.sign_permit(&permit, &domain).unwrap());
+        assert_eq!(signature.recover(&permit.signing_hash(&domain)).unwrap(), signer.address());
+        assert_ne!(signature.recover(&keccak256(b"other")).ok(), Some(signer.address()));
+
+        let order = LimitOrderBuilder::new()
+            .maker(signer.address())
+            .maker_asset(Address::from_bytes([0x55; 20]))
+            .taker_asset(Address::from_bytes([0x1d; 20]))
+            .making_amount(100u64.into())
+            .taking_amount(50u64.into())
+            .build()
+            .unwrap();
+        let signature = signer.sign_limit_order(&order, SupportedNetworks::BSC).unwrap();
+        assert_eq!(signature.recover(&order.hash(SupportedNetworks::BSC)).unwrap(), signer.address());
+
+        let request = SubmitOrderRequest::new(order, &signature, SupportedNetworks::BSC);
+        assert_eq!(request.signature.len(), 65);
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    client::SupportedNetworks,
+    common::{
+        address::Address,
+        eip712::{Eip712, Eip712Domain},
+    },
+    orderbook::LimitOrder,
+    transaction::Transaction,
+    utils::keccak::keccak256,
+};
+use k256::ecdsa::VerifyingKey;
+use std::fmt::Debug;
+use thiserror::Error;
+
+mod local;
+mod permit;
+mod signature;
+
+pub use local::*;
+pub use permit::*;
+pub use signature::*;
+
+/// Enumerates potential errors when signing or recovering signatures.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum SignerError {
+    /// Indicates the private key is not a valid secp256k1 scalar.
+    #[error("Invalid private key")]
+    InvalidPrivateKey,
+
+    /// Indicates the signature is malformed or can't be recovered.
+    #[error("Invalid signature")]
+    InvalidSignature,
+
+    /// Indicates the signer failed to produce a signature.
+    #[error("Signing failed: {0}")]
+    Signing(String),
+}
+
+/// Account able to sign hashes with its secp256k1 key.
+///
+/// Implementors only provide the address and hash signing, messages, typed
+/// data and orders are signed on top of them.
+pub trait Signer: Debug + Send + Sync {
+    /// Returns address of the account.
+    fn address(&self) -> Address;
+
+    /// Signs the 32-byte hash as is.
+    fn sign_hash(&self, hash: &[u8; 32]) -> Result<Signature, SignerError>;
+
+    /// Signs the message according to
+    /// [EIP-191](https://eips.ethereum.org/EIPS/eip-191), as `personal_sign`
+    /// does.
+    fn sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.sign_hash(&hash_message(message))
+    }
+
+    /// Signs the typed data according to
+    /// [EIP-712](https://eips.ethereum.org/EIPS/eip-712).
+    fn sign_typed_data<T: Eip712>(&self, data: &T, domain: &Eip712Domain) -> Result<Signature, SignerError>
+    where
+        Self: Sized,
+    {
+        self.sign_hash(&data.signing_hash(domain))
+    }
+
+    /// Signs the limit order for the Limit Order Protocol in the network.
+    fn sign_limit_order(&self, order: &LimitOrder, network: SupportedNetworks) -> Result<Signature, SignerError> {
+        self.sign_hash(&order.hash(network))
+    }
+
+    /// Signs the transaction, the signed raw transaction can be built with
+    /// [`Transaction::encode_signed`] or [`Transaction::sign`].
+    fn sign_transaction(&self, transaction: &Transaction) -> Result<Signature, SignerError> {
+        self.sign_hash(&transaction.signing_hash())
+    }
+
+    /// Signs the [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612) permit
+    /// for the token with the given domain.
+    fn sign_permit(&self, permit: &Eip2612Permit, domain: &Eip712Domain) -> Result<Signature, SignerError> {
+        self.sign_hash(&permit.signing_hash(domain))
+    }
+}
+
+/// Computes EIP-191 hash of the message, which is signed by `personal_sign`.
+pub fn hash_message(message: &[u8]) -> [u8; 32] {
+    let mut data = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    data.extend_from_slice(message);
+    keccak256(data)
+}
+
+// Derives address from the public key: the last 20 bytes of the keccak hash
+// of the uncompressed key without its prefix.
+pub(crate) fn address_from_verifying_key(key: &VerifyingKey) -> Address {
+    let point = key.to_encoded_point(false);
+    let hash = keccak256(&point.as_bytes()[1..]);
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address.into()
+}
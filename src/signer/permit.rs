@@ -0,0 +1,65 @@
+use crate::{
+    client::SupportedNetworks,
+    common::{
+        address::Address,
+        amount::TokenAmount,
+        bytes::Bytes,
+        eip712::{encode_address, encode_u64, encode_uint, Eip712, Eip712Domain},
+    },
+    signer::Signature,
+};
+
+/// [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612) permit, allowing the
+/// spender to transfer the owner's tokens without an approve transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip2612Permit {
+    pub owner: Address,
+    pub spender: Address,
+    pub value: TokenAmount,
+
+    /// Current permit nonce of the owner in the token contract.
+    pub nonce: TokenAmount,
+
+    /// Unix timestamp after which the permit can't be used.
+    pub deadline: u64,
+}
+
+impl Eip2612Permit {
+    /// Returns EIP-712 domain of the token, its name and version can be found
+    /// in [`TokenInfo`](crate::common::token::TokenInfo) or the token contract.
+    pub fn domain(name: &str, version: &str, network: SupportedNetworks, token: Address) -> Eip712Domain {
+        Eip712Domain::new(name, version, network.chain_id().into(), token)
+    }
+
+    /// Encodes the signed permit as arguments of the token's `permit` call,
+    /// the form expected by the `permit` parameter of the swap API.
+    pub fn encode_call_args(&self, signature: &Signature) -> Bytes {
+        [
+            encode_address(&self.owner),
+            encode_address(&self.spender),
+            encode_uint(&self.value),
+            encode_u64(self.deadline),
+            encode_u64(signature.v().into()),
+            signature.r,
+            signature.s,
+        ]
+        .concat()
+        .into()
+    }
+}
+
+impl Eip712 for Eip2612Permit {
+    fn type_string() -> &'static str {
+        "Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+    }
+
+    fn encode_data(&self) -> Vec<[u8; 32]> {
+        vec![
+            encode_address(&self.owner),
+            encode_address(&self.spender),
+            encode_uint(&self.value),
+            encode_uint(&self.nonce),
+            encode_u64(self.deadline),
+        ]
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    common::{address::Address, bytes::Bytes},
+    signer::{address_from_verifying_key, SignerError},
+};
+use k256::ecdsa::{RecoveryId, Signature as EcdsaSignature, VerifyingKey};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// Recoverable secp256k1 signature.
+///
+/// `Display` and serde use the 65-byte `r ‖ s ‖ v` form with `v` being 27 or
+/// 28, which is what the 1inch APIs expect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Signature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+
+    /// Parity of the `y` coordinate of `R`, also known as recovery id.
+    pub y_parity: bool,
+}
+
+impl Signature {
+    /// Returns `v` value in the `27 + y_parity` form.
+    pub fn v(&self) -> u8 {
+        27 + self.y_parity as u8
+    }
+
+    /// Returns 65-byte `r ‖ s ‖ v` encoding.
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..64].copy_from_slice(&self.s);
+        bytes[64] = self.v();
+        bytes
+    }
+
+    /// Returns 64-byte [EIP-2098](https://eips.ethereum.org/EIPS/eip-2098)
+    /// compact `r ‖ vs` encoding, where the parity is stored in the highest
+    /// bit of `s`.
+    pub fn to_compact(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..].copy_from_slice(&self.s);
+        bytes[32] |= (self.y_parity as u8) << 7;
+        bytes
+    }
+
+    /// Parses 65-byte `r ‖ s ‖ v` encoding, `v` can be either 0/1 or 27/28.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SignerError> {
+        if bytes.len() != 65 {
+            return Err(SignerError::InvalidSignature);
+        }
+
+        let y_parity = match bytes[64] {
+            0 | 27 => false,
+            1 | 28 => true,
+            _ => return Err(SignerError::InvalidSignature),
+        };
+
+        let mut r = [0u8; 32];
+        let mut s = [0u8; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..64]);
+
+        Ok(Signature { r, s, y_parity })
+    }
+
+    /// Recovers address of the account which signed the hash.
+    pub fn recover(&self, hash: &[u8; 32]) -> Result<Address, SignerError> {
+        let mut rs = [0u8; 64];
+        rs[..32].copy_from_slice(&self.r);
+        rs[32..].copy_from_slice(&self.s);
+
+        let signature = EcdsaSignature::from_slice(&rs).map_err(|_| SignerError::InvalidSignature)?;
+        let recovery_id = RecoveryId::new(self.y_parity, false);
+        let key = VerifyingKey::recover_from_prehash(hash, &signature, recovery_id).map_err(|_| SignerError::InvalidSignature)?;
+
+        Ok(address_from_verifying_key(&key))
+    }
+}
+
+impl From<Signature> for Bytes {
+    fn from(signature: Signature) -> Self {
+        signature.to_bytes().to_vec().into()
+    }
+}
+
+impl FromStr for Signature {
+    type Err = SignerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = Bytes::from_str(s).map_err(|_| SignerError::InvalidSignature)?;
+        Signature::from_bytes(&bytes)
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Bytes::from(*self))
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Signature::from_str(&s).map_err(de::Error::custom)
+    }
+}
@@ -1,31 +1,23 @@
 use crate::{
     client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
+    error::OneInchError,
     swap::approve::{AllowanceDetails, AllowanceResponse},
+    utils::request::parse_url_with_params,
 };
-use reqwest::Url;
-use std::error::Error;
 
 impl OneInchClient {
     /// Retrieves the current allowance for a token on the specified account.
-    pub async fn get_allowance(&self, details: AllowanceDetails) -> Result<AllowanceResponse, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/approve/allowance", BASIC_URL, SWAP_API_VERSION, self.network_id);
+    pub async fn get_allowance(&self, details: AllowanceDetails) -> Result<AllowanceResponse, OneInchError> {
+        let url = format!("{}/swap/{}/{}/approve/allowance", self.base_url, self.swap_api_version, self.network_id);
 
-        let url_with_params = Url::parse_with_params(
+        let url_with_params = parse_url_with_params(
             &url,
-            &[("tokenAddress", details.token_address), ("walletAddress", details.wallet_address)],
-        )
-        .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+            &[
+                ("tokenAddress", format!("{:#x}", details.token_address)),
+                ("walletAddress", format!("{:#x}", details.wallet_address)),
+            ],
+        )?;
 
-        let request_result = self.http_client.get(url_with_params).header("Authorization", &self.token).send().await;
-
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let allowance_response: AllowanceResponse = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        Ok(allowance_response)
+        self.get_json(url_with_params).await
     }
 }
@@ -1,35 +1,46 @@
 use crate::{
     client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
+    error::OneInchError,
     swap::approve::{ApproveCallData, ApproveTranactionDetails},
-    utils::params::insert_optional_param,
+    utils::{params::insert_optional_param, request::parse_url_with_params},
 };
-use reqwest::Url;
-use std::error::Error;
 
 impl OneInchClient {
     /// Performs request to get `ApproveCallData` for specific token, account
     /// and amount. Actually we will get a raw transaction which changes the
     /// required token`s allowance value to a specified amount on executing.
-    pub async fn approve(&self, details: ApproveTranactionDetails) -> Result<ApproveCallData, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/approve/transaction", BASIC_URL, SWAP_API_VERSION, self.network_id);
+    pub async fn approve(&self, details: ApproveTranactionDetails) -> Result<ApproveCallData, OneInchError> {
+        let url = format!("{}/swap/{}/{}/approve/transaction", self.base_url, self.swap_api_version, self.network_id);
 
         let mut params: Vec<(&str, String)> =
-            vec![("chain", self.network_id.to_string()), ("tokenAddress", details.token_address)];
+            vec![("chain", self.network_id.to_string()), ("tokenAddress", format!("{:#x}", details.token_address))];
 
-        insert_optional_param(&mut params, "amount", details.amount);
+        insert_optional_param(&mut params, "amount", details.amount.map(|a| a.to_string()));
 
-        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let url_with_params = parse_url_with_params(&url, params)?;
 
-        let request_result = self.http_client.get(url_with_params).header("Authorization", &self.token).send().await;
-
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let approve_response: ApproveCallData = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        self.get_json(url_with_params).await
+    }
+}
 
-        Ok(approve_response)
+#[cfg(test)]
+mod tests {
+    use crate::{
+        swap::approve::ApproveTranactionDetailsBuilder,
+        transport::{testing::*, Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_approve_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(
+            Mock::get("/swap/v5.2/56/approve/transaction").query_param("tokenAddress", USDT).json_body(
+                r#"{"data":"0x095ea7b3","gasPrice":"3000000000","to":"0x55d398326f99059ff775485246999027b3197955","value":"0"}"#,
+            ),
+        );
+
+        let details = ApproveTranactionDetailsBuilder::new().token_address(USDT.parse().unwrap()).amount(None).build().unwrap();
+        let approve = client_with(&transport).approve(details).await.unwrap();
+        assert_eq!(approve.data, "0x095ea7b3");
     }
 }
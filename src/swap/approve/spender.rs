@@ -1,29 +1,12 @@
-use crate::{
-    client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
-    swap::approve::RouterAddress,
-};
-use std::error::Error;
+use crate::{client::OneInchClient, error::OneInchError, swap::approve::RouterAddress, utils::request::parse_url};
 
 impl OneInchClient {
     /// Retrieves the router address for the specified network.
-    pub async fn get_router_address(&self) -> Result<RouterAddress, Box<dyn Error>> {
+    pub async fn get_router_address(&self) -> Result<RouterAddress, OneInchError> {
         // Construct the URL for fetching router address.
-        let url = format!("{}/swap/{}/{}/approve/spender", BASIC_URL, SWAP_API_VERSION, self.network_id);
+        let url = format!("{}/swap/{}/{}/approve/spender", self.base_url, self.swap_api_version, self.network_id);
 
-        // Send HTTP GET request with authorization header.
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
-
-        // Handle request errors and check for successful response.
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        // Parse JSON response into RouterAddress type.
-        let address: RouterAddress = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        // Return the obtained router address.
-        Ok(address)
+        // Send authorized request and parse JSON response into RouterAddress type.
+        self.get_json(parse_url(&url)?).await
     }
 }
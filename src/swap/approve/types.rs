@@ -1,20 +1,24 @@
-use crate::{builder_setter, utils::builder::BasicBuilderError};
+use crate::{
+    builder_setter,
+    common::{address::Address, amount::TokenAmount},
+    utils::builder::BasicBuilderError,
+};
 use serde::Deserialize;
 
 /// Builder struct to create instance of `AllowanceDetails`
 #[derive(Default)]
 pub struct AllowanceDetailsBuilder {
-    token_address: Option<String>,
-    wallet_address: Option<String>,
+    token_address: Option<Address>,
+    wallet_address: Option<Address>,
 }
 
 /// Represents the details required for an approve/allowance request.
 #[derive(Debug, Clone)]
 pub struct AllowanceDetails {
     /// Address of the token contract for which to retrieve allowance.
-    pub token_address: String,
+    pub token_address: Address,
     /// Address of the wallet for which to get allowance.
-    pub wallet_address: String,
+    pub wallet_address: Address,
 }
 
 impl AllowanceDetailsBuilder {
@@ -22,8 +26,8 @@ impl AllowanceDetailsBuilder {
         AllowanceDetailsBuilder::default()
     }
 
-    builder_setter!(token_address, String);
-    builder_setter!(wallet_address, String);
+    builder_setter!(token_address, Address);
+    builder_setter!(wallet_address, Address);
 
     /// Attempts to construct a
     /// [`AllowanceDetails`](crate::swap::approve::AllowanceDetails) from the
@@ -31,8 +35,8 @@ impl AllowanceDetailsBuilder {
     /// values are incorrect.
     pub fn build(&self) -> Result<AllowanceDetails, BasicBuilderError> {
         Ok(AllowanceDetails {
-            wallet_address: self.wallet_address.clone().ok_or(BasicBuilderError::MissingField("wallet_address"))?,
-            token_address: self.token_address.clone().ok_or(BasicBuilderError::MissingField("token_address"))?,
+            wallet_address: self.wallet_address.ok_or(BasicBuilderError::MissingField("wallet_address"))?,
+            token_address: self.token_address.ok_or(BasicBuilderError::MissingField("token_address"))?,
         })
     }
 }
@@ -41,20 +45,20 @@ impl AllowanceDetailsBuilder {
 /// Allowance.
 #[derive(Deserialize, Debug)]
 pub struct AllowanceResponse {
-    pub allowance: String,
+    pub allowance: TokenAmount,
 }
 
 /// Struct represents router address as 1inch returns it.
 #[derive(Debug, Clone, Deserialize)]
 pub struct RouterAddress {
-    pub address: String,
+    pub address: Address,
 }
 
 /// Builder struct to create instance of `ApproveTranactionDetails`
 #[derive(Default)]
 pub struct ApproveTranactionDetailsBuilder {
-    token_address: Option<String>,
-    amount: Option<Option<String>>,
+    token_address: Option<Address>,
+    amount: Option<Option<TokenAmount>>,
 }
 
 impl ApproveTranactionDetailsBuilder {
@@ -62,8 +66,8 @@ impl ApproveTranactionDetailsBuilder {
         ApproveTranactionDetailsBuilder::default()
     }
 
-    builder_setter!(token_address, String);
-    builder_setter!(amount, Option<String>);
+    builder_setter!(token_address, Address);
+    builder_setter!(amount, Option<TokenAmount>);
 
     /// Attempts to construct a
     /// [`ApproveTranactionDetails`](crate::swap::approve::ApproveTranactionDetails)
@@ -71,7 +75,7 @@ impl ApproveTranactionDetailsBuilder {
     /// some of values are incorrect.
     pub fn build(&self) -> Result<ApproveTranactionDetails, BasicBuilderError> {
         Ok(ApproveTranactionDetails {
-            token_address: self.token_address.clone().ok_or(BasicBuilderError::MissingField("token_address"))?,
+            token_address: self.token_address.ok_or(BasicBuilderError::MissingField("token_address"))?,
             amount: self.amount.clone().ok_or(BasicBuilderError::MissingField("amount"))?,
         })
     }
@@ -81,12 +85,16 @@ impl ApproveTranactionDetailsBuilder {
 /// amount with value `None` will mean that you want set maximal allowance.
 #[derive(Debug, Clone)]
 pub struct ApproveTranactionDetails {
-    pub token_address: String,
-    pub amount: Option<String>,
+    pub token_address: Address,
+    pub amount: Option<TokenAmount>,
 }
 
 /// Struct represents data to make an approve transaction as server returns it.
 /// Includes raw transaction and other data to perform tx.
+///
+/// The approve endpoint doesn't take a gas price, so fees of a gas price tier
+/// are applied to the transaction built from this data, with
+/// [`TransactionBuilder::gas_price_tier`](crate::transaction::TransactionBuilder::gas_price_tier).
 #[derive(Debug, Clone, Deserialize)]
 pub struct ApproveCallData {
     pub data: String,
@@ -94,8 +102,8 @@ pub struct ApproveCallData {
     #[serde(rename = "gasPrice")]
     pub gas_price: String,
 
-    pub to: String,
-    pub value: String,
+    pub to: Address,
+    pub value: TokenAmount,
 }
 
 #[cfg(test)]
@@ -106,26 +114,31 @@ mod tests {
     fn test_approve_transaction_details_builder() {
         let approve_details = ApproveTranactionDetailsBuilder::new()
             .amount(None)
-            .token_address("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string())
+            .token_address("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".parse().unwrap())
             .build()
             .unwrap();
 
-        assert_eq!(&approve_details.token_address, "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        assert_eq!(
+            format!("{:#x}", approve_details.token_address),
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+        );
         assert_eq!(approve_details.amount.clone(), None);
     }
 
     #[test]
     fn test_allowance_details_builder() {
         let allowance_details = AllowanceDetailsBuilder::new()
-            .wallet_address("0x30A557351eab496FD69F537BE1F8c744A18F94Fd".into())
-            .token_address("0x55d398326f99059ff775485246999027b3197955".into())
+            .wallet_address("0x30A557351eab496FD69F537BE1F8c744A18F94Fd".parse().unwrap())
+            .token_address("0x55d398326f99059ff775485246999027b3197955".parse().unwrap())
             .build()
             .unwrap();
 
-        assert_eq!(&allowance_details.token_address, "0x55d398326f99059ff775485246999027b3197955");
-        assert_eq!(&allowance_details.wallet_address, "0x30A557351eab496FD69F537BE1F8c744A18F94Fd");
+        assert_eq!(
+            format!("{:#x}", allowance_details.token_address),
+            "0x55d398326f99059ff775485246999027b3197955"
+        );
+        assert_eq!(allowance_details.wallet_address.to_string(), "0x30A557351eab496FD69F537BE1F8c744A18F94Fd");
 
-        assert_ne!(&allowance_details.token_address, "Everything another than address.");
-        assert_ne!(&allowance_details.wallet_address, "Everything another than address.");
+        assert!("Everything another than address.".parse::<Address>().is_err());
     }
 }
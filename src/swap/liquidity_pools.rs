@@ -1,9 +1,5 @@
-use crate::{
-    client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
-};
+use crate::{client::OneInchClient, error::OneInchError, utils::request::parse_url};
 use serde::Deserialize;
-use std::error::Error;
 
 /// LiquidityProtocolImage is struct that defines information about LP source.
 #[derive(Deserialize, Debug)]
@@ -25,19 +21,9 @@ pub struct LiquidityProtocolsResponse {
 impl OneInchClient {
     /// Get current list of liquidity sources that are available for routing in
     /// 1inch.
-    pub async fn get_liquidity_sources(&self) -> Result<LiquidityProtocolsResponse, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/liquidity-sources", BASIC_URL, SWAP_API_VERSION, self.network_id);
+    pub async fn get_liquidity_sources(&self) -> Result<LiquidityProtocolsResponse, OneInchError> {
+        let url = format!("{}/swap/{}/{}/liquidity-sources", self.base_url, self.swap_api_version, self.network_id);
 
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
-
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let liquidity_sources_response: LiquidityProtocolsResponse =
-            response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        Ok(liquidity_sources_response)
+        self.get_json(parse_url(&url)?).await
     }
 }
@@ -1,4 +1,5 @@
 mod liquidity_pools;
+#[allow(clippy::module_inception)]
 mod swap;
 mod tokens_list;
 
@@ -6,7 +7,12 @@ mod tokens_list;
 pub mod approve;
 mod quote;
 mod types;
+mod version;
+
+/// Requests and responses specific to the swap API v6.0.
+pub mod v6;
 
 pub use liquidity_pools::*;
 pub use tokens_list::*;
 pub use types::*;
+pub use version::*;
@@ -1,20 +1,27 @@
-use std::error::Error;
-
 use crate::{
     client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
-    swap::{QuoteDetails, QuoteResponse, SwapError, SwapRequestError},
-    utils::params::insert_optional_param,
+    consts::SWAP_API_VERSION,
+    error::OneInchError,
+    swap::{v6, QuoteDetails, QuoteResponse, SwapApiVersion},
+    utils::{params::insert_optional_param, request::parse_url_with_params},
 };
-use reqwest::Url;
 
 impl OneInchClient {
-    /// Performs `quote` request with predefined parameters.
-    pub async fn quote(&self, details: QuoteDetails) -> Result<QuoteResponse, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/quote/", BASIC_URL, SWAP_API_VERSION, self.network_id);
+    /// Performs `quote` request with predefined parameters, using the swap API
+    /// version the client was built with.
+    pub async fn quote(&self, details: QuoteDetails) -> Result<QuoteResponse, OneInchError> {
+        if self.swap_api_version == SwapApiVersion::V6_0 {
+            return self.quote_v6(v6::QuoteDetails::from(details)).await.map(QuoteResponse::from);
+        }
+
+        let url = format!("{}/swap/{}/{}/quote/", self.base_url, SWAP_API_VERSION, self.network_id);
 
         // Adding required parameters
-        let mut params: Vec<(&str, String)> = vec![("src", details.src), ("dst", details.dst), ("amount", details.amount)];
+        let mut params: Vec<(&str, String)> = vec![
+            ("src", format!("{:#x}", details.src)),
+            ("dst", format!("{:#x}", details.dst)),
+            ("amount", details.amount.to_string()),
+        ];
 
         // Adding optional bool parameters
         insert_optional_param(&mut params, "includeGas", details.include_gas.map(|a| a.to_string()));
@@ -33,36 +40,38 @@ impl OneInchClient {
         insert_optional_param(&mut params, "gasPrice", details.gas_price);
         insert_optional_param(&mut params, "connectorTokens", details.connector_tokens);
 
-        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let url_with_params = parse_url_with_params(&url, params)?;
+
+        self.get_json(url_with_params).await
+    }
+}
 
-        let response = match self.http_client.get(url_with_params).header("Authorization", &self.token).send().await {
-            Ok(response) => response,
-            Err(e) => return Err(SwapError::Network(e).into()),
-        };
+#[cfg(test)]
+mod tests {
+    use crate::{
+        swap::QuoteDetailsBuilder,
+        transport::{testing::*, Mock, MockTransport},
+    };
 
-        if response.status().as_u16() == 400 {
-            let error_body = response.text().await.unwrap_or_default();
-            return match serde_json::from_str::<SwapRequestError>(&error_body) {
-                Ok(err) => Err(SwapError::SwapRequest {
-                    description: err.description,
-                    error: err.error,
-                    status_code: err.status_code,
-                    request_id: err.request_id,
-                }
-                .into()),
-                Err(e) => Err(SwapError::Other(format!("Error parsing error response: {}", e)).into()),
-            };
-        }
+    #[tokio::test]
+    async fn test_quote_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(
+            Mock::get("/swap/v5.2/56/quote").query_param("src", USDT).json_body(r#"{"toAmount":"2100000000000000000"}"#),
+        );
 
-        if response.status().is_client_error() || response.status().is_server_error() {
-            return Err(SwapError::Other(format!("Server responded with error: {}", response.status())).into());
-        }
+        let details = QuoteDetailsBuilder::new()
+            .src(USDT.parse().unwrap())
+            .dst(XRP.parse().unwrap())
+            .amount("1000000000000000000".parse().unwrap())
+            .build()
+            .unwrap();
 
-        let quote_data: QuoteResponse = match response.json().await {
-            Ok(data) => data,
-            Err(e) => return Err(SwapError::Network(e).into()),
-        };
+        let quote = client_with(&transport).quote(details).await.unwrap();
+        assert_eq!(quote.to_amount.to_units(18), "2.1");
 
-        Ok(quote_data)
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].headers.get("Authorization").unwrap(), "test-token");
     }
 }
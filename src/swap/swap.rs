@@ -1,25 +1,28 @@
-use std::error::Error;
-
 use crate::{
     client::OneInchClient,
-    consts::{BASIC_URL, SWAP_API_VERSION},
-    swap::{SwapDetails, SwapError, SwapRequestError, SwapResponse},
-    utils::params::insert_optional_param,
+    consts::SWAP_API_VERSION,
+    error::OneInchError,
+    swap::{v6, SwapApiVersion, SwapDetails, SwapResponse},
+    utils::{params::insert_optional_param, request::parse_url_with_params},
 };
-use reqwest::Url;
 
 impl OneInchClient {
-    /// Performs swap request with predefined parameters.
-    pub async fn swap(&self, details: SwapDetails) -> Result<SwapResponse, Box<dyn Error>> {
-        let url = format!("{}/swap/{}/{}/swap/", BASIC_URL, SWAP_API_VERSION, self.network_id);
+    /// Performs swap request with predefined parameters, using the swap API
+    /// version the client was built with.
+    pub async fn swap(&self, details: SwapDetails) -> Result<SwapResponse, OneInchError> {
+        if self.swap_api_version == SwapApiVersion::V6_0 {
+            return self.swap_v6(v6::SwapDetails::from(details)).await.map(SwapResponse::from);
+        }
+
+        let url = format!("{}/swap/{}/{}/swap/", self.base_url, SWAP_API_VERSION, self.network_id);
 
         // Adding required parameters
         let mut params: Vec<(&str, String)> = vec![
-            ("from", details.from),
+            ("from", format!("{:#x}", details.from)),
             ("slippage", details.slippage.to_string()),
-            ("src", details.src),
-            ("dst", details.dst),
-            ("amount", details.amount),
+            ("src", format!("{:#x}", details.src)),
+            ("dst", format!("{:#x}", details.dst)),
+            ("amount", details.amount.to_string()),
         ];
 
         // Adding optional bool parameters
@@ -41,39 +44,42 @@ impl OneInchClient {
         insert_optional_param(&mut params, "gasPrice", details.gas_price);
         insert_optional_param(&mut params, "connectorTokens", details.connector_tokens);
         insert_optional_param(&mut params, "permit", details.permit);
-        insert_optional_param(&mut params, "receiver", details.receiver);
-        insert_optional_param(&mut params, "referrer", details.referrer);
+        insert_optional_param(&mut params, "receiver", details.receiver.map(|a| format!("{:#x}", a)));
+        insert_optional_param(&mut params, "referrer", details.referrer.map(|a| format!("{:#x}", a)));
 
-        let url_with_params = Url::parse_with_params(&url, params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let url_with_params = parse_url_with_params(&url, params)?;
 
-        let response = match self.http_client.get(url_with_params).header("Authorization", &self.token).send().await {
-            Ok(response) => response,
-            Err(e) => return Err(SwapError::Network(e).into()),
-        };
+        self.get_json(url_with_params).await
+    }
+}
 
-        if response.status().as_u16() == 400 {
-            let error_body = response.text().await.unwrap_or_default();
-            return match serde_json::from_str::<SwapRequestError>(&error_body) {
-                Ok(err) => Err(SwapError::SwapRequest {
-                    description: err.description,
-                    error: err.error,
-                    status_code: err.status_code,
-                    request_id: err.request_id,
-                }
-                .into()),
-                Err(e) => Err(SwapError::Other(format!("Error parsing error response: {}", e)).into()),
-            };
-        }
+#[cfg(test)]
+mod tests {
+    use crate::{
+        swap::SwapDetailsBuilder,
+        transport::{testing::*, Mock, MockTransport},
+    };
 
-        if response.status().is_client_error() || response.status().is_server_error() {
-            return Err(SwapError::Other(format!("Server responded with error: {}", response.status())).into());
-        }
+    #[tokio::test]
+    async fn test_swap_api_error_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(Mock::get("/swap/v5.2/56/swap").status(400).json_body(
+            r#"{"error":"Bad Request","description":"Not enough allowance","statusCode":400,"requestId":"42","meta":[]}"#,
+        ));
 
-        let swap_data: SwapResponse = match response.json().await {
-            Ok(data) => data,
-            Err(e) => return Err(SwapError::Network(e).into()),
-        };
+        let details = SwapDetailsBuilder::new()
+            .src(XRP.parse().unwrap())
+            .dst(USDT.parse().unwrap())
+            .amount(1000u64.into())
+            .from_addr(WALLET.parse().unwrap())
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
 
-        Ok(swap_data)
+        let error = client_with(&transport).swap(details).await.unwrap_err();
+        assert_eq!(error.status(), Some(400));
+        assert_eq!(error.api_error().unwrap().description, "Not enough allowance");
+        assert_eq!(error.api_error().unwrap().request_id, "42");
     }
 }
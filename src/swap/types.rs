@@ -1,6 +1,6 @@
-use crate::builder_setter;
+use crate::{builder_setter, gas_price_tier_setter};
 
-use crate::common::token::TokenInfo;
+use crate::common::{address::Address, amount::TokenAmount, token::TokenInfo};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -33,11 +33,11 @@ pub enum QuoteDetailsBuilderError {
 /// Represents the details required for performing a token swap.
 #[derive(Debug, Clone)]
 pub struct SwapDetails {
-    pub src: String,     // Source token address.
-    pub dst: String,     // Destination token address.
-    pub amount: String,  // Amount to be swapped.
-    pub from: String,    // Address of the user initiating the swap.
-    pub slippage: usize, // Permitted slippage percentage.
+    pub src: Address,        // Source token address.
+    pub dst: Address,        // Destination token address.
+    pub amount: TokenAmount, // Amount to be swapped.
+    pub from: Address,       // Address of the user initiating the swap.
+    pub slippage: usize,     // Permitted slippage percentage.
 
     // Optional fields
     pub fee: Option<u8>,
@@ -53,8 +53,8 @@ pub struct SwapDetails {
     pub include_gas: Option<bool>,
     pub connector_tokens: Option<String>,
     pub permit: Option<String>,
-    pub receiver: Option<String>,
-    pub referrer: Option<String>,
+    pub receiver: Option<Address>,
+    pub referrer: Option<Address>,
 
     pub disable_estimate: Option<bool>,   // If true, disables estimation.
     pub allow_partial_fill: Option<bool>, // If true, allows the swap to be partially filled.
@@ -63,10 +63,10 @@ pub struct SwapDetails {
 /// A builder pattern implementation for creating a `SwapDetails`.
 #[derive(Default)]
 pub struct SwapDetailsBuilder {
-    src: Option<String>,
-    dst: Option<String>,
-    amount: Option<String>,
-    from_addr: Option<String>,
+    src: Option<Address>,
+    dst: Option<Address>,
+    amount: Option<TokenAmount>,
+    from_addr: Option<Address>,
     slippage: Option<usize>,
 
     // Optional fields
@@ -83,8 +83,8 @@ pub struct SwapDetailsBuilder {
     include_gas: Option<bool>,
     connector_tokens: Option<String>,
     permit: Option<String>,
-    receiver: Option<String>,
-    referrer: Option<String>,
+    receiver: Option<Address>,
+    referrer: Option<Address>,
 
     disable_estimate: Option<bool>,   // If true, disables estimation.
     allow_partial_fill: Option<bool>, // If true, allows the swap to be partially filled.
@@ -100,7 +100,7 @@ pub struct SwapResponse {
     pub to_token: Option<TokenInfo>,
 
     #[serde(rename = "toAmount")]
-    pub to_amount: String,
+    pub to_amount: TokenAmount,
 
     pub protocols: Option<Vec<Vec<Vec<SelectedProtocol>>>>,
 
@@ -112,10 +112,10 @@ pub struct SwapResponse {
 /// representation of raw_tranaction to perform swap on blockchain.
 #[derive(Deserialize, Debug)]
 pub struct SwapTranactionData {
-    pub from: String,
-    pub to: String,
+    pub from: Address,
+    pub to: Address,
     pub data: String,
-    pub value: String,
+    pub value: TokenAmount,
 
     #[serde(rename = "gasPrice")]
     pub gas_price: String,
@@ -123,93 +123,16 @@ pub struct SwapTranactionData {
     pub gas: u128,
 }
 
-/// Represents errors that can occur during both swap or quote request.
-/// We use the same struct to handle errors that may occur with `swap` and
-/// `quote` requests because the possible errors are almost identical. This enum
-/// aggregates various types of errors related to these operations,
-/// including HTTP requests, JSON parsing, and swap API specific errors.
-#[derive(Error, Debug)]
-pub enum SwapError {
-    /// Error related to network requests.
-    ///
-    /// Used for handling issues with network requests, such as server
-    /// unavailability, network connectivity problems, etc.
-    #[error("Network error: {0}")]
-    Network(reqwest::Error),
-
-    /// Error while parsing JSON.
-    ///
-    /// Occurs when the server's response cannot be correctly deserialized from
-    /// JSON. This could happen if the response format is different than
-    /// expected.
-    #[error("JSON parsing error: {0}")]
-    JsonParse(serde_json::Error),
-
-    /// Specific error related to swap/quote API.
-    ///
-    /// Represents errors specific to the swap API, like insufficient funds or
-    /// invalid request parameters.
-    #[error("Swap request error: {description}")]
-    SwapRequest { description: String, error: String, status_code: u16, request_id: String },
-
-    /// A general error.
-    ///
-    /// Used for other types of errors that do not fit into the above
-    /// categories.
-    #[error("Other error: {0}")]
-    Other(String),
-}
-
-/// Represents an error response from the swap/quote API.
-///
-/// This structure is used to deserialize the JSON error response from the both
-/// swap/quote API. It contains details about the error that occurred during a
-/// request.
-#[derive(serde::Deserialize)]
-pub struct SwapRequestError {
-    /// A brief description of the error.
-    pub error: String,
-
-    /// A more detailed description of the error.
-    pub description: String,
-
-    /// The HTTP status code associated with the error.
-    #[serde(rename = "statusCode")]
-    pub status_code: u16,
-
-    /// A unique identifier for the request, useful for debugging.
-    #[serde(rename = "requestId")]
-    pub request_id: String,
-
-    /// Additional metadata related to the error, if any.
-    pub meta: Vec<HttpExceptionMeta>,
-}
-
-/// Represents additional metadata in the swap API error response.
-///
-/// Each item in the `meta` field of `SwapRequestError` will be deserialized
-/// into this structure. It provides more context about the error, such as the
-/// affected parameters or values.
-#[derive(serde::Deserialize)]
-pub struct HttpExceptionMeta {
-    /// The type of metadata.
-    #[serde(rename = "type")]
-    pub type_field: String,
-
-    /// The value associated with this metadata.
-    pub value: String,
-}
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SelectedProtocol {
     pub name: String,
     pub part: f64,
 
     #[serde(rename = "fromTokenAddress")]
-    pub from_token_address: String,
+    pub from_token_address: Address,
 
     #[serde(rename = "toTokenAddress")]
-    pub to_token_address: String,
+    pub to_token_address: Address,
 }
 
 impl SwapDetailsBuilder {
@@ -218,13 +141,15 @@ impl SwapDetailsBuilder {
         SwapDetailsBuilder::default()
     }
 
-    builder_setter!(src, String);
-    builder_setter!(dst, String);
-    builder_setter!(amount, String);
-    builder_setter!(from_addr, String);
+    builder_setter!(src, Address);
+    builder_setter!(dst, Address);
+    builder_setter!(amount, TokenAmount);
+    builder_setter!(from_addr, Address);
 
     builder_setter!(protocols, String);
     builder_setter!(gas_price, String);
+    gas_price_tier_setter!();
+
     builder_setter!(complexity_level, u128);
     builder_setter!(parts, u128);
     builder_setter!(main_route_parts, u128);
@@ -236,8 +161,8 @@ impl SwapDetailsBuilder {
 
     builder_setter!(connector_tokens, String);
     builder_setter!(permit, String);
-    builder_setter!(receiver, String);
-    builder_setter!(referrer, String);
+    builder_setter!(receiver, Address);
+    builder_setter!(referrer, Address);
 
     builder_setter!(disable_estimate, bool);
     builder_setter!(allow_partial_fill, bool);
@@ -268,7 +193,7 @@ impl SwapDetailsBuilder {
         Ok(SwapDetails {
             src: self.src.ok_or(SwapDetailsBuilderError::MissingField("src"))?,
             dst: self.dst.ok_or(SwapDetailsBuilderError::MissingField("dst"))?,
-            amount: self.amount.ok_or(SwapDetailsBuilderError::MissingField("amount"))?.to_string(),
+            amount: self.amount.ok_or(SwapDetailsBuilderError::MissingField("amount"))?,
             from: self.from_addr.ok_or(SwapDetailsBuilderError::MissingField("from_addr"))?,
             slippage: self.slippage.ok_or(SwapDetailsBuilderError::MissingField("slippage"))?,
 
@@ -295,9 +220,9 @@ impl SwapDetailsBuilder {
 /// QuoteDetails is struct that contains data we need to perform /quote request.
 #[derive(Debug, Clone)]
 pub struct QuoteDetails {
-    pub src: String,    // Source token address.
-    pub dst: String,    // Destination token address.
-    pub amount: String, // Amount to be swapped.
+    pub src: Address,        // Source token address.
+    pub dst: Address,        // Destination token address.
+    pub amount: TokenAmount, // Amount to be swapped.
 
     // Optional fields
     pub fee: Option<u8>,
@@ -317,9 +242,9 @@ pub struct QuoteDetails {
 /// QuoteDetailsBuilder is struct to create instance of `QuoteDetails`
 #[derive(Default)]
 pub struct QuoteDetailsBuilder {
-    pub src: Option<String>,
-    pub dst: Option<String>,
-    pub amount: Option<String>,
+    pub src: Option<Address>,
+    pub dst: Option<Address>,
+    pub amount: Option<TokenAmount>,
 
     // Optional fields
     pub fee: Option<u8>,
@@ -341,12 +266,14 @@ impl QuoteDetailsBuilder {
         QuoteDetailsBuilder::default()
     }
 
-    builder_setter!(src, String);
-    builder_setter!(dst, String);
-    builder_setter!(amount, String);
+    builder_setter!(src, Address);
+    builder_setter!(dst, Address);
+    builder_setter!(amount, TokenAmount);
 
     builder_setter!(protocols, String);
     builder_setter!(gas_price, String);
+    gas_price_tier_setter!();
+
     builder_setter!(complexity_level, u128);
     builder_setter!(parts, u128);
     builder_setter!(main_route_parts, u128);
@@ -373,7 +300,7 @@ impl QuoteDetailsBuilder {
         Ok(QuoteDetails {
             src: self.src.ok_or(QuoteDetailsBuilderError::MissingField("src"))?,
             dst: self.dst.ok_or(QuoteDetailsBuilderError::MissingField("dst"))?,
-            amount: self.amount.ok_or(QuoteDetailsBuilderError::MissingField("amount"))?.to_string(),
+            amount: self.amount.ok_or(QuoteDetailsBuilderError::MissingField("amount"))?,
 
             fee: self.fee,
             protocols: self.protocols,
@@ -400,7 +327,7 @@ pub struct QuoteResponse {
     pub to_token: Option<TokenInfo>,
 
     #[serde(rename = "toAmount")]
-    pub to_amount: String,
+    pub to_amount: TokenAmount,
     pub protocols: Option<Vec<Vec<Vec<SelectedProtocol>>>>,
 }
 
@@ -409,14 +336,18 @@ pub struct QuoteResponse {
 mod tests {
     use super::*;
 
+    const FROM_TOKEN: Address = Address::NATIVE_TOKEN;
+    const TO_TOKEN: Address = Address::from_bytes([0x55; 20]);
+    const FROM_ADDR: Address = Address::from_bytes([0x13; 20]);
+
     /// Tests a successful construction of `SwapDetails` using the builder.
     #[test]
     fn test_valid_swap_details_builder() {
         let swap_details = SwapDetailsBuilder::new()
-            .src("from_token".to_string())
-            .dst("to_token".to_string())
-            .amount("1000".to_string())
-            .from_addr("from_addr".to_string())
+            .src(FROM_TOKEN)
+            .dst(TO_TOKEN)
+            .amount(TokenAmount::from(1000u64))
+            .from_addr(FROM_ADDR)
             .slippage(5)
             .expect("Invalid slippage")
             .disable_estimate(false)
@@ -424,10 +355,10 @@ mod tests {
             .build()
             .expect("Failed to build SwapDetails");
 
-        assert_eq!(swap_details.src, "from_token");
-        assert_eq!(swap_details.dst, "to_token");
-        assert_eq!(swap_details.amount, "1000");
-        assert_eq!(swap_details.from, "from_addr");
+        assert_eq!(swap_details.src, FROM_TOKEN);
+        assert_eq!(swap_details.dst, TO_TOKEN);
+        assert_eq!(swap_details.amount, TokenAmount::from(1000u64));
+        assert_eq!(swap_details.from, FROM_ADDR);
         assert_eq!(swap_details.slippage, 5);
         assert!(!swap_details.disable_estimate.unwrap());
         assert!(!swap_details.allow_partial_fill.unwrap());
@@ -437,10 +368,10 @@ mod tests {
     #[test]
     fn test_invalid_slippage_in_builder() {
         let result = SwapDetailsBuilder::new()
-            .src("from_token".to_string())
-            .dst("to_token".to_string())
-            .amount("1000".to_string())
-            .from_addr("from_addr".to_string())
+            .src(FROM_TOKEN)
+            .dst(TO_TOKEN)
+            .amount(TokenAmount::from(1000u64))
+            .from_addr(FROM_ADDR)
             .slippage(102);
 
         assert!(result.is_err());
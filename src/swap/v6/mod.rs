@@ -0,0 +1,6 @@
+mod quote;
+#[allow(clippy::module_inception)]
+mod swap;
+mod types;
+
+pub use types::*;
@@ -0,0 +1,44 @@
+use crate::{
+    client::OneInchClient,
+    consts::SWAP_API_V6_VERSION,
+    error::OneInchError,
+    swap::v6::{QuoteDetails, QuoteResponse},
+    utils::{params::insert_optional_param, request::parse_url_with_params},
+};
+
+impl OneInchClient {
+    /// Performs `quote` request against the swap API v6.0, regardless of the
+    /// client's configured swap API version.
+    pub async fn quote_v6(&self, details: QuoteDetails) -> Result<QuoteResponse, OneInchError> {
+        let url = format!("{}/swap/{}/{}/quote", self.base_url, SWAP_API_V6_VERSION, self.network_id);
+
+        // Adding required parameters
+        let mut params: Vec<(&str, String)> = vec![
+            ("src", format!("{:#x}", details.src)),
+            ("dst", format!("{:#x}", details.dst)),
+            ("amount", details.amount.to_string()),
+        ];
+
+        // Adding optional bool parameters
+        insert_optional_param(&mut params, "includeGas", details.include_gas.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "includeProtocols", details.include_protocols.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "includeTokensInfo", details.include_tokens_info.map(|a| a.to_string()));
+
+        // Adding optional num parameters
+        insert_optional_param(&mut params, "fee", details.fee.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "complexityLevel", details.complexity_level.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "parts", details.parts.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "mainRouteParts", details.main_route_parts.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "gasLimit", details.gas_limit.map(|a| a.to_string()));
+
+        // Adding optional string parameters
+        insert_optional_param(&mut params, "protocols", details.protocols);
+        insert_optional_param(&mut params, "excludedProtocols", details.excluded_protocols);
+        insert_optional_param(&mut params, "gasPrice", details.gas_price);
+        insert_optional_param(&mut params, "connectorTokens", details.connector_tokens);
+
+        let url_with_params = parse_url_with_params(&url, params)?;
+
+        self.get_json(url_with_params).await
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    client::OneInchClient,
+    consts::SWAP_API_V6_VERSION,
+    error::OneInchError,
+    swap::v6::{SwapDetails, SwapResponse},
+    utils::{params::insert_optional_param, request::parse_url_with_params},
+};
+
+impl OneInchClient {
+    /// Performs swap request against the swap API v6.0, regardless of the
+    /// client's configured swap API version.
+    pub async fn swap_v6(&self, details: SwapDetails) -> Result<SwapResponse, OneInchError> {
+        let url = format!("{}/swap/{}/{}/swap", self.base_url, SWAP_API_V6_VERSION, self.network_id);
+
+        // Adding required parameters
+        let mut params: Vec<(&str, String)> = vec![
+            ("from", format!("{:#x}", details.from)),
+            ("slippage", details.slippage.to_string()),
+            ("src", format!("{:#x}", details.src)),
+            ("dst", format!("{:#x}", details.dst)),
+            ("amount", details.amount.to_string()),
+        ];
+
+        // Adding optional bool parameters
+        insert_optional_param(&mut params, "disableEstimate", details.disable_estimate.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "allowPartialFill", details.allow_partial_fill.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "includeGas", details.include_gas.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "includeProtocols", details.include_protocols.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "includeTokensInfo", details.include_tokens_info.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "usePermit2", details.use_permit2.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "compatibility", details.compatibility.map(|a| a.to_string()));
+
+        // Adding optional num parameters
+        insert_optional_param(&mut params, "fee", details.fee.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "complexityLevel", details.complexity_level.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "parts", details.parts.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "mainRouteParts", details.main_route_parts.map(|a| a.to_string()));
+        insert_optional_param(&mut params, "gasLimit", details.gas_limit.map(|a| a.to_string()));
+
+        // Adding optional string parameters
+        insert_optional_param(&mut params, "protocols", details.protocols);
+        insert_optional_param(&mut params, "excludedProtocols", details.excluded_protocols);
+        insert_optional_param(&mut params, "gasPrice", details.gas_price);
+        insert_optional_param(&mut params, "connectorTokens", details.connector_tokens);
+        insert_optional_param(&mut params, "permit", details.permit);
+        insert_optional_param(&mut params, "origin", details.origin.map(|a| format!("{:#x}", a)));
+        insert_optional_param(&mut params, "receiver", details.receiver.map(|a| format!("{:#x}", a)));
+        insert_optional_param(&mut params, "referrer", details.referrer.map(|a| format!("{:#x}", a)));
+
+        let url_with_params = parse_url_with_params(&url, params)?;
+
+        self.get_json(url_with_params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::SupportedNetworks,
+        swap::{v6, QuoteDetailsBuilder, SwapApiVersion},
+        transport::{testing::*, Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_swap_api_v6_with_mock() {
+        let transport = MockTransport::new();
+        transport
+            .register(Mock::get("/swap/v6.0/56/quote").query_param("src", USDT).json_body(r#"{"dstAmount":"42","gas":150000}"#))
+            .register(Mock::get("/swap/v6.0/56/swap").query_param("origin", WALLET).query_param("usePermit2", "true").json_body(
+                format!(
+                    r#"{{"dstAmount":"41","tx":{{"from":"{}","to":"{}","data":"0x","value":"0","gasPrice":"3000000000","gas":200000}}}}"#,
+                    WALLET, USDT
+                ),
+            ))
+            .register(Mock::get("/swap/v6.0/56/approve/spender").json_body(format!(r#"{{"address":"{}"}}"#, WALLET)));
+
+        let client = OneInchClient::builder()
+            .token("test-token".to_string())
+            .network_id(SupportedNetworks::BSC)
+            .swap_api_version(SwapApiVersion::V6_0)
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        // High-level API is routed to v6.0 and converted to the common response
+        let details = QuoteDetailsBuilder::new()
+            .src(USDT.parse().unwrap())
+            .dst(XRP.parse().unwrap())
+            .amount(1000u64.into())
+            .build()
+            .unwrap();
+        assert_eq!(client.quote(details).await.unwrap().to_amount, 42u64.into());
+
+        let details = v6::SwapDetailsBuilder::new()
+            .src(USDT.parse().unwrap())
+            .dst(XRP.parse().unwrap())
+            .amount(1000u64.into())
+            .from_addr(WALLET.parse().unwrap())
+            .origin(WALLET.parse().unwrap())
+            .use_permit2(true)
+            .slippage(1)
+            .unwrap()
+            .build()
+            .unwrap();
+        let swap = client.swap_v6(details).await.unwrap();
+        assert_eq!(swap.dst_amount, 41u64.into());
+        assert_eq!(swap.transaction.gas, 200000);
+
+        let spender = client.get_router_address().await.unwrap();
+        assert_eq!(spender.address, WALLET.parse().unwrap());
+    }
+}
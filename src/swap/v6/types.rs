@@ -0,0 +1,386 @@
+use crate::{
+    builder_setter,
+    common::{address::Address, amount::TokenAmount, token::TokenInfo},
+    gas_price_tier_setter,
+    swap::{QuoteDetailsBuilderError, SelectedProtocol, SwapDetailsBuilderError, SwapTranactionData},
+};
+use serde::Deserialize;
+
+/// Represents the details required for performing a token swap with the swap
+/// API v6.0.
+#[derive(Debug, Clone)]
+pub struct SwapDetails {
+    pub src: Address,        // Source token address.
+    pub dst: Address,        // Destination token address.
+    pub amount: TokenAmount, // Amount to be swapped.
+    pub from: Address,       // Address of the user initiating the swap.
+    pub slippage: usize,     // Permitted slippage percentage.
+
+    // Optional fields
+    pub origin: Option<Address>, // EOA initiating the transaction, if it's not `from`.
+    pub fee: Option<u8>,
+    pub protocols: Option<String>,
+    pub excluded_protocols: Option<String>,
+    pub gas_price: Option<String>,
+    pub complexity_level: Option<u128>,
+    pub parts: Option<u128>,
+    pub main_route_parts: Option<u128>,
+    pub gas_limit: Option<u128>,
+
+    pub include_tokens_info: Option<bool>,
+    pub include_protocols: Option<bool>,
+    pub include_gas: Option<bool>,
+    pub connector_tokens: Option<String>,
+    pub permit: Option<String>,
+    pub receiver: Option<Address>,
+    pub referrer: Option<Address>,
+
+    pub disable_estimate: Option<bool>,   // If true, disables estimation.
+    pub allow_partial_fill: Option<bool>, // If true, allows the swap to be partially filled.
+    pub use_permit2: Option<bool>,        // If true, permit is a Permit2 signature.
+    pub compatibility: Option<bool>,      // If true, calldata is built without optimized routers.
+}
+
+/// A builder pattern implementation for creating a v6.0 `SwapDetails`.
+#[derive(Default)]
+pub struct SwapDetailsBuilder {
+    src: Option<Address>,
+    dst: Option<Address>,
+    amount: Option<TokenAmount>,
+    from_addr: Option<Address>,
+    slippage: Option<usize>,
+
+    // Optional fields
+    origin: Option<Address>,
+    fee: Option<u8>,
+    protocols: Option<String>,
+    excluded_protocols: Option<String>,
+    gas_price: Option<String>,
+    complexity_level: Option<u128>,
+    parts: Option<u128>,
+    main_route_parts: Option<u128>,
+    gas_limit: Option<u128>,
+
+    include_tokens_info: Option<bool>,
+    include_protocols: Option<bool>,
+    include_gas: Option<bool>,
+    connector_tokens: Option<String>,
+    permit: Option<String>,
+    receiver: Option<Address>,
+    referrer: Option<Address>,
+
+    disable_estimate: Option<bool>,
+    allow_partial_fill: Option<bool>,
+    use_permit2: Option<bool>,
+    compatibility: Option<bool>,
+}
+
+impl SwapDetailsBuilder {
+    /// Constructs a new `SwapDetailsBuilder` with all fields uninitialized.
+    pub fn new() -> Self {
+        SwapDetailsBuilder::default()
+    }
+
+    builder_setter!(src, Address);
+    builder_setter!(dst, Address);
+    builder_setter!(amount, TokenAmount);
+    builder_setter!(from_addr, Address);
+
+    builder_setter!(origin, Address);
+    builder_setter!(protocols, String);
+    builder_setter!(excluded_protocols, String);
+    builder_setter!(gas_price, String);
+    gas_price_tier_setter!();
+
+    builder_setter!(complexity_level, u128);
+    builder_setter!(parts, u128);
+    builder_setter!(main_route_parts, u128);
+    builder_setter!(gas_limit, u128);
+
+    builder_setter!(include_tokens_info, bool);
+    builder_setter!(include_protocols, bool);
+    builder_setter!(include_gas, bool);
+
+    builder_setter!(connector_tokens, String);
+    builder_setter!(permit, String);
+    builder_setter!(receiver, Address);
+    builder_setter!(referrer, Address);
+
+    builder_setter!(disable_estimate, bool);
+    builder_setter!(allow_partial_fill, bool);
+    builder_setter!(use_permit2, bool);
+    builder_setter!(compatibility, bool);
+
+    /// Special setter for fee that ensures value is within allowable range.
+    pub fn fee(mut self, fee: u8) -> Result<Self, SwapDetailsBuilderError> {
+        if fee > 3 {
+            return Err(SwapDetailsBuilderError::InvalidFee);
+        }
+        self.fee = Some(fee);
+        Ok(self)
+    }
+
+    /// Special setter for slippage that ensures value is within allowable
+    /// range.
+    pub fn slippage(mut self, slippage: usize) -> Result<Self, SwapDetailsBuilderError> {
+        if slippage > 50 {
+            return Err(SwapDetailsBuilderError::InvalidSlippage);
+        }
+        self.slippage = Some(slippage);
+        Ok(self)
+    }
+
+    /// Attempts to construct a [`SwapDetails`](crate::swap::v6::SwapDetails)
+    /// from the builder, returning errors if required fields are missing or if
+    /// some of values are incorrect.
+    pub fn build(self) -> Result<SwapDetails, SwapDetailsBuilderError> {
+        Ok(SwapDetails {
+            src: self.src.ok_or(SwapDetailsBuilderError::MissingField("src"))?,
+            dst: self.dst.ok_or(SwapDetailsBuilderError::MissingField("dst"))?,
+            amount: self.amount.ok_or(SwapDetailsBuilderError::MissingField("amount"))?,
+            from: self.from_addr.ok_or(SwapDetailsBuilderError::MissingField("from_addr"))?,
+            slippage: self.slippage.ok_or(SwapDetailsBuilderError::MissingField("slippage"))?,
+
+            origin: self.origin,
+            fee: self.fee,
+            protocols: self.protocols,
+            excluded_protocols: self.excluded_protocols,
+            gas_price: self.gas_price,
+            complexity_level: self.complexity_level,
+            parts: self.parts,
+            main_route_parts: self.main_route_parts,
+            gas_limit: self.gas_limit,
+            include_tokens_info: self.include_tokens_info,
+            include_protocols: self.include_protocols,
+            include_gas: self.include_gas,
+            connector_tokens: self.connector_tokens,
+            permit: self.permit,
+            receiver: self.receiver,
+            referrer: self.referrer,
+            disable_estimate: self.disable_estimate,
+            allow_partial_fill: self.allow_partial_fill,
+            use_permit2: self.use_permit2,
+            compatibility: self.compatibility,
+        })
+    }
+}
+
+/// QuoteDetails is struct that contains data we need to perform v6.0 /quote
+/// request.
+#[derive(Debug, Clone)]
+pub struct QuoteDetails {
+    pub src: Address,        // Source token address.
+    pub dst: Address,        // Destination token address.
+    pub amount: TokenAmount, // Amount to be swapped.
+
+    // Optional fields
+    pub fee: Option<u8>,
+    pub protocols: Option<String>,
+    pub excluded_protocols: Option<String>,
+    pub gas_price: Option<String>,
+    pub complexity_level: Option<u128>,
+    pub parts: Option<u128>,
+    pub main_route_parts: Option<u128>,
+    pub gas_limit: Option<u128>,
+
+    pub include_tokens_info: Option<bool>,
+    pub include_protocols: Option<bool>,
+    pub include_gas: Option<bool>,
+    pub connector_tokens: Option<String>,
+}
+
+/// QuoteDetailsBuilder is struct to create instance of v6.0 `QuoteDetails`
+#[derive(Default)]
+pub struct QuoteDetailsBuilder {
+    src: Option<Address>,
+    dst: Option<Address>,
+    amount: Option<TokenAmount>,
+
+    // Optional fields
+    fee: Option<u8>,
+    protocols: Option<String>,
+    excluded_protocols: Option<String>,
+    gas_price: Option<String>,
+    complexity_level: Option<u128>,
+    parts: Option<u128>,
+    main_route_parts: Option<u128>,
+    gas_limit: Option<u128>,
+
+    include_tokens_info: Option<bool>,
+    include_protocols: Option<bool>,
+    include_gas: Option<bool>,
+    connector_tokens: Option<String>,
+}
+
+impl QuoteDetailsBuilder {
+    pub fn new() -> Self {
+        QuoteDetailsBuilder::default()
+    }
+
+    builder_setter!(src, Address);
+    builder_setter!(dst, Address);
+    builder_setter!(amount, TokenAmount);
+
+    builder_setter!(protocols, String);
+    builder_setter!(excluded_protocols, String);
+    builder_setter!(gas_price, String);
+    gas_price_tier_setter!();
+
+    builder_setter!(complexity_level, u128);
+    builder_setter!(parts, u128);
+    builder_setter!(main_route_parts, u128);
+    builder_setter!(gas_limit, u128);
+
+    builder_setter!(include_tokens_info, bool);
+    builder_setter!(include_protocols, bool);
+    builder_setter!(include_gas, bool);
+    builder_setter!(connector_tokens, String);
+
+    /// Special setter for fee that ensures value is within allowable range.
+    pub fn fee(mut self, fee: u8) -> Result<Self, QuoteDetailsBuilderError> {
+        if fee > 3 {
+            return Err(QuoteDetailsBuilderError::InvalidFee);
+        }
+        self.fee = Some(fee);
+        Ok(self)
+    }
+
+    /// Attempts to construct a [`QuoteDetails`](crate::swap::v6::QuoteDetails)
+    /// from the builder, returning errors if required fields are missing or if
+    /// some of values are incorrect.
+    pub fn build(self) -> Result<QuoteDetails, QuoteDetailsBuilderError> {
+        Ok(QuoteDetails {
+            src: self.src.ok_or(QuoteDetailsBuilderError::MissingField("src"))?,
+            dst: self.dst.ok_or(QuoteDetailsBuilderError::MissingField("dst"))?,
+            amount: self.amount.ok_or(QuoteDetailsBuilderError::MissingField("amount"))?,
+
+            fee: self.fee,
+            protocols: self.protocols,
+            excluded_protocols: self.excluded_protocols,
+            gas_price: self.gas_price,
+            complexity_level: self.complexity_level,
+            parts: self.parts,
+            main_route_parts: self.main_route_parts,
+            gas_limit: self.gas_limit,
+            include_tokens_info: self.include_tokens_info,
+            include_protocols: self.include_protocols,
+            include_gas: self.include_gas,
+            connector_tokens: self.connector_tokens,
+        })
+    }
+}
+
+/// QuoteResponse is a struct to deserialize data we can get on v6.0 quote
+/// request.
+#[derive(Deserialize, Debug)]
+pub struct QuoteResponse {
+    #[serde(rename = "srcToken")]
+    pub src_token: Option<TokenInfo>,
+
+    #[serde(rename = "dstToken")]
+    pub dst_token: Option<TokenInfo>,
+
+    #[serde(rename = "dstAmount")]
+    pub dst_amount: TokenAmount,
+
+    pub protocols: Option<Vec<Vec<Vec<SelectedProtocol>>>>,
+
+    /// Estimated gas, returned only if `include_gas` is set.
+    pub gas: Option<u128>,
+}
+
+/// SwapResponse is a struct to deserialize data we can get on v6.0 swap
+/// request.
+#[derive(Deserialize, Debug)]
+pub struct SwapResponse {
+    #[serde(rename = "srcToken")]
+    pub src_token: Option<TokenInfo>,
+
+    #[serde(rename = "dstToken")]
+    pub dst_token: Option<TokenInfo>,
+
+    #[serde(rename = "dstAmount")]
+    pub dst_amount: TokenAmount,
+
+    pub protocols: Option<Vec<Vec<Vec<SelectedProtocol>>>>,
+
+    #[serde(rename = "tx")]
+    pub transaction: SwapTranactionData,
+}
+
+impl From<crate::swap::QuoteDetails> for QuoteDetails {
+    fn from(details: crate::swap::QuoteDetails) -> Self {
+        QuoteDetails {
+            src: details.src,
+            dst: details.dst,
+            amount: details.amount,
+            fee: details.fee,
+            protocols: details.protocols,
+            excluded_protocols: None,
+            gas_price: details.gas_price,
+            complexity_level: details.complexity_level,
+            parts: details.parts,
+            main_route_parts: details.main_route_parts,
+            gas_limit: details.gas_limit,
+            include_tokens_info: details.include_tokens_info,
+            include_protocols: details.include_protocols,
+            include_gas: details.include_gas,
+            connector_tokens: details.connector_tokens,
+        }
+    }
+}
+
+impl From<crate::swap::SwapDetails> for SwapDetails {
+    fn from(details: crate::swap::SwapDetails) -> Self {
+        SwapDetails {
+            src: details.src,
+            dst: details.dst,
+            amount: details.amount,
+            from: details.from,
+            slippage: details.slippage,
+            origin: None,
+            fee: details.fee,
+            protocols: details.protocols,
+            excluded_protocols: None,
+            gas_price: details.gas_price,
+            complexity_level: details.complexity_level,
+            parts: details.parts,
+            main_route_parts: details.main_route_parts,
+            gas_limit: details.gas_limit,
+            include_tokens_info: details.include_tokens_info,
+            include_protocols: details.include_protocols,
+            include_gas: details.include_gas,
+            connector_tokens: details.connector_tokens,
+            permit: details.permit,
+            receiver: details.receiver,
+            referrer: details.referrer,
+            disable_estimate: details.disable_estimate,
+            allow_partial_fill: details.allow_partial_fill,
+            use_permit2: None,
+            compatibility: None,
+        }
+    }
+}
+
+impl From<QuoteResponse> for crate::swap::QuoteResponse {
+    fn from(response: QuoteResponse) -> Self {
+        crate::swap::QuoteResponse {
+            from_token: response.src_token,
+            to_token: response.dst_token,
+            to_amount: response.dst_amount,
+            protocols: response.protocols,
+        }
+    }
+}
+
+impl From<SwapResponse> for crate::swap::SwapResponse {
+    fn from(response: SwapResponse) -> Self {
+        crate::swap::SwapResponse {
+            from_token: response.src_token,
+            to_token: response.dst_token,
+            to_amount: response.dst_amount,
+            protocols: response.protocols,
+            transaction: response.transaction,
+        }
+    }
+}
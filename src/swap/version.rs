@@ -0,0 +1,30 @@
+use crate::consts::{SWAP_API_V6_VERSION, SWAP_API_VERSION};
+use std::fmt;
+
+/// Versions of the swap API the client can work with.
+///
+/// The version is selected per client with
+/// [`OneInchClientBuilder::swap_api_version`](crate::client::OneInchClientBuilder::swap_api_version)
+/// and is used by all the `swap/*` endpoints, including the high-level
+/// [`quote`](crate::client::OneInchClient::quote) and
+/// [`swap`](crate::client::OneInchClient::swap) methods. Version specific
+/// parameters are available through [`quote_v6`](crate::client::OneInchClient::quote_v6)
+/// and [`swap_v6`](crate::client::OneInchClient::swap_v6).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum SwapApiVersion {
+    /// Swap API v5.2, working with the 1inch Aggregation Router v5.
+    #[default]
+    V5_2,
+
+    /// Swap API v6.0, working with the 1inch Aggregation Router v6.
+    V6_0,
+}
+
+impl fmt::Display for SwapApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SwapApiVersion::V5_2 => f.write_str(SWAP_API_VERSION),
+            SwapApiVersion::V6_0 => f.write_str(SWAP_API_V6_VERSION),
+        }
+    }
+}
@@ -1,10 +1,6 @@
 use serde::Deserialize;
 
-use crate::{
-    client::OneInchClient,
-    consts::{BASIC_URL, SPOT_PRICE_API_VERSION},
-};
-use std::error::Error;
+use crate::{client::OneInchClient, consts::SPOT_PRICE_API_VERSION, error::OneInchError, utils::request::parse_url};
 
 /// `CurrenciesResponse` is a struct that defines a list of supported currencies
 /// (like USD, UAH, SEK, etc) as 1inch returns it. In fact, it's a
@@ -18,17 +14,9 @@ pub struct CurrenciesResponse {
 
 impl OneInchClient {
     /// Get current list of currencies
-    pub async fn get_custom_currencies(&self) -> Result<CurrenciesResponse, Box<dyn Error>> {
-        let url = format!("{}/price/{}/{}/currencies", BASIC_URL, SPOT_PRICE_API_VERSION, self.network_id);
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
+    pub async fn get_custom_currencies(&self) -> Result<CurrenciesResponse, OneInchError> {
+        let url = format!("{}/price/{}/{}/currencies", self.base_url, SPOT_PRICE_API_VERSION, self.network_id);
 
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        let currencies_list_response: CurrenciesResponse = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
-
-        Ok(currencies_list_response)
+        self.get_json(parse_url(&url)?).await
     }
 }
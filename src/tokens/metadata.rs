@@ -0,0 +1,149 @@
+use crate::{
+    builder_setter,
+    client::OneInchClient,
+    common::{address::Address, token::TokenInfo},
+    consts::TOKENS_API_VERSION,
+    error::OneInchError,
+    utils::{
+        builder::BasicBuilderError,
+        params::insert_optional_param,
+        request::{parse_url, parse_url_with_params},
+    },
+};
+use std::collections::HashMap;
+
+/// Builder struct to create instance of
+/// [`TokenSearchDetails`](crate::tokens::metadata::TokenSearchDetails)
+#[derive(Default)]
+pub struct TokenSearchDetailsBuilder {
+    query: Option<String>,
+    ignore_listed: Option<bool>,
+    only_positive_rating: Option<bool>,
+    limit: Option<u32>,
+}
+
+impl TokenSearchDetailsBuilder {
+    pub fn new() -> Self {
+        TokenSearchDetailsBuilder::default()
+    }
+
+    builder_setter!(query, String);
+    builder_setter!(ignore_listed, bool);
+    builder_setter!(only_positive_rating, bool);
+    builder_setter!(limit, u32);
+
+    /// Attempts to construct a
+    /// [`TokenSearchDetails`](crate::tokens::metadata::TokenSearchDetails)
+    /// from the builder, returning errors if required fields are missing.
+    pub fn build(self) -> Result<TokenSearchDetails, BasicBuilderError> {
+        Ok(TokenSearchDetails {
+            query: self.query.ok_or(BasicBuilderError::MissingField("query"))?,
+            ignore_listed: self.ignore_listed,
+            only_positive_rating: self.only_positive_rating,
+            limit: self.limit,
+        })
+    }
+}
+
+/// Details of the token search by name, symbol or address.
+#[derive(Debug, Clone)]
+pub struct TokenSearchDetails {
+    pub query: String,
+
+    /// Searches only among the tokens not listed by 1inch.
+    pub ignore_listed: Option<bool>,
+    pub only_positive_rating: Option<bool>,
+    pub limit: Option<u32>,
+}
+
+impl OneInchClient {
+    /// Performs request to search tokens of the network.
+    pub async fn search_tokens(&self, details: TokenSearchDetails) -> Result<Vec<TokenInfo>, OneInchError> {
+        let url = format!("{}/token/{}/{}/search", self.base_url, TOKENS_API_VERSION, self.network_id);
+
+        self.get_json(parse_url_with_params(&url, token_search_params(details))?).await
+    }
+
+    /// Performs request to search tokens across all the networks.
+    pub async fn search_tokens_multichain(&self, details: TokenSearchDetails) -> Result<Vec<TokenInfo>, OneInchError> {
+        let url = format!("{}/token/{}/search", self.base_url, TOKENS_API_VERSION);
+
+        self.get_json(parse_url_with_params(&url, token_search_params(details))?).await
+    }
+
+    /// Performs request to get metadata of the tokens, including the ones
+    /// not listed by 1inch.
+    pub async fn get_custom_tokens(&self, addresses: &[Address]) -> Result<HashMap<Address, TokenInfo>, OneInchError> {
+        let url = format!("{}/token/{}/{}/custom", self.base_url, TOKENS_API_VERSION, self.network_id);
+
+        let addresses = addresses.iter().map(|a| format!("{:#x}", a)).collect::<Vec<String>>().join(",");
+        self.get_json(parse_url_with_params(&url, [("addresses", addresses)])?).await
+    }
+
+    /// Performs request to get metadata of the token, including the ones not
+    /// listed by 1inch.
+    pub async fn get_custom_token(&self, address: Address) -> Result<TokenInfo, OneInchError> {
+        let url = format!("{}/token/{}/{}/custom/{:#x}", self.base_url, TOKENS_API_VERSION, self.network_id, address);
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get all the tokens of the network listed by 1inch.
+    pub async fn get_listed_tokens(&self) -> Result<HashMap<Address, TokenInfo>, OneInchError> {
+        let url = format!("{}/token/{}/{}", self.base_url, TOKENS_API_VERSION, self.network_id);
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get the tokens listed by 1inch in all the networks.
+    pub async fn get_multichain_tokens(&self) -> Result<Vec<TokenInfo>, OneInchError> {
+        let url = format!("{}/token/{}/multi-chain", self.base_url, TOKENS_API_VERSION);
+
+        self.get_json(parse_url(&url)?).await
+    }
+}
+
+fn token_search_params(details: TokenSearchDetails) -> Vec<(&'static str, String)> {
+    let mut params = vec![("query", details.query)];
+    insert_optional_param(&mut params, "ignore_listed", details.ignore_listed.map(|v| v.to_string()));
+    insert_optional_param(&mut params, "only_positive_rating", details.only_positive_rating.map(|v| v.to_string()));
+    insert_optional_param(&mut params, "limit", details.limit.map(|v| v.to_string()));
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{testing::*, Mock, MockTransport};
+
+    #[tokio::test]
+    async fn test_token_api_with_mock() {
+        let token = format!(
+            r#"{{"chainId":56,"symbol":"XRP","name":"XRP Token","address":"{}","decimals":18,"logoURI":null,"providers":["1inch"],"rating":8,"eip2612":false,"isFoT":false,"tags":["tokens"]}}"#,
+            XRP
+        );
+        let transport = MockTransport::new();
+        transport
+            .register(
+                Mock::get("/token/v1.2/56/search")
+                    .query_param("query", "xrp")
+                    .query_param("limit", "1")
+                    .json_body(format!("[{}]", token)),
+            )
+            .register(
+                Mock::get("/token/v1.2/56/custom").query_param("addresses", XRP).json_body(format!(r#"{{"{}":{}}}"#, XRP, token)),
+            );
+
+        let client = client_with(&transport);
+
+        let details = TokenSearchDetailsBuilder::new().query("xrp".into()).limit(1).build().unwrap();
+        let tokens = client.search_tokens(details).await.unwrap();
+        assert_eq!(tokens[0].rating, Some(8));
+        assert_eq!(tokens[0].providers, vec!["1inch"]);
+        assert!(tokens[0].logo_uri.is_none());
+
+        let xrp: Address = XRP.parse().unwrap();
+        let custom = client.get_custom_tokens(&[xrp]).await.unwrap();
+        assert_eq!(custom[&xrp].chain_id, Some(56));
+    }
+}
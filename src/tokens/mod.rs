@@ -1,2 +1,3 @@
 pub mod currencies;
+pub mod metadata;
 pub mod tokens_price;
@@ -1,18 +1,34 @@
 use crate::{
     builder_setter, client,
     client::OneInchClient,
-    consts::{BASIC_URL, SPOT_PRICE_API_VERSION},
-    utils::builder::BasicBuilderError,
+    common::address::Address,
+    consts::SPOT_PRICE_API_VERSION,
+    error::OneInchError,
+    utils::{
+        builder::BasicBuilderError,
+        params::insert_optional_param,
+        request::{parse_url, parse_url_with_params},
+    },
 };
-use reqwest::Url;
+pub use bigdecimal::BigDecimal;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, error::Error};
+use std::collections::HashMap;
+
+// Number of addresses per request: GET requests are limited by the url
+// length, POST ones by the request size.
+const GET_PRICES_CHUNK_SIZE: usize = 50;
+const POST_PRICES_CHUNK_SIZE: usize = 1000;
+
+// Number of chunk requests in flight at once, so long lists don't turn into
+// a burst of requests hitting the rate limit of the API key.
+const MAX_CONCURRENT_PRICES_REQUESTS: usize = 4;
 
 /// Builder struct to create instance of
 /// [`TokensPricesRequestDetails`](crate::tokens::tokens_price::TokensPricesRequestDetails)
 #[derive(Default)]
 pub struct TokensPricesRequestBuilder {
-    addresses: Option<Vec<String>>,
+    addresses: Option<Vec<Address>>,
     currency: Option<client::SupportedCurrencies>,
 }
 
@@ -21,7 +37,7 @@ impl TokensPricesRequestBuilder {
         TokensPricesRequestBuilder::default()
     }
 
-    builder_setter!(addresses, Vec<String>);
+    builder_setter!(addresses, Vec<Address>);
     builder_setter!(currency, client::SupportedCurrencies);
 
     /// Attempts to construct a
@@ -39,53 +55,197 @@ impl TokensPricesRequestBuilder {
 /// Currency `None` means that price will returned in native Wei of blockchain.
 #[derive(Debug, Clone)]
 pub struct TokensPricesRequestDetails {
-    pub addresses: Vec<String>,
+    pub addresses: Vec<Address>,
     pub currency: Option<client::SupportedCurrencies>,
 }
 
 /// Represents the struct we receive after making request to get current prices
 /// for specified tokens. In fact response is just a hashmap where key is
-/// token`s address and value its price in selected currency. Note : 1inch
-/// always returns tokens addresses in lowercase.
-#[derive(Serialize, Deserialize, Debug)]
+/// token`s address and value its price in selected currency.
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct TokenPricesResponse {
     #[serde(flatten)]
-    pub prices: HashMap<String, String>,
+    pub prices: HashMap<Address, BigDecimal>,
+}
+
+// Body of the POST request for prices.
+#[derive(Serialize, Debug)]
+struct TokensPricesRequest {
+    tokens: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    currency: Option<String>,
 }
 
 impl OneInchClient {
     /// Performs request to get price of specified tokens in specified currency.
-    pub async fn get_tokens_price(&self, details: TokensPricesRequestDetails) -> Result<TokenPricesResponse, Box<dyn Error>> {
-        let base_url = format!("{}/price/{}/{}/", BASIC_URL, SPOT_PRICE_API_VERSION, self.network_id);
+    ///
+    /// Addresses are passed in the url, so long lists are split into several
+    /// requests, which responses are merged. At most 4 of the requests are
+    /// sent at once.
+    pub async fn get_tokens_price(&self, details: TokensPricesRequestDetails) -> Result<TokenPricesResponse, OneInchError> {
+        let base_url = format!("{}/price/{}/{}/", self.base_url, SPOT_PRICE_API_VERSION, self.network_id);
 
-        let comma_separated_addresses = details
-            .addresses
-            .iter()
-            .map(|addr| addr.to_string())
-            .map(|addr| addr.to_lowercase())
-            .collect::<Vec<String>>()
-            .join(",");
+        let requests = details.addresses.chunks(GET_PRICES_CHUNK_SIZE).map(|addresses| {
+            let comma_separated_addresses =
+                addresses.iter().map(|addr| format!("{:#x}", addr)).collect::<Vec<String>>().join(",");
+
+            let mut url_with_params = format!("{}{}", base_url, comma_separated_addresses);
+
+            // Adding `currency` param if not None
+            if let Some(currency) = &details.currency {
+                url_with_params = format!("{}?currency={}", url_with_params, currency);
+            }
+
+            async move { self.get_json::<TokenPricesResponse>(parse_url(&url_with_params)?).await }
+        });
+
+        let responses = stream::iter(requests).buffered(MAX_CONCURRENT_PRICES_REQUESTS).try_collect().await?;
+        Ok(merge_prices(responses))
+    }
+
+    /// Same as [`get_tokens_price`](OneInchClient::get_tokens_price), but
+    /// passes addresses in the request body, which allows much larger batches
+    /// per request. At most 4 of the requests are sent at once.
+    pub async fn post_tokens_price(&self, details: TokensPricesRequestDetails) -> Result<TokenPricesResponse, OneInchError> {
+        let url = parse_url(&format!("{}/price/{}/{}", self.base_url, SPOT_PRICE_API_VERSION, self.network_id))?;
+
+        let requests = details.addresses.chunks(POST_PRICES_CHUNK_SIZE).map(|addresses| {
+            let body = TokensPricesRequest {
+                tokens: addresses.iter().map(|addr| format!("{:#x}", addr)).collect(),
+                currency: details.currency.as_ref().map(|c| c.to_string()),
+            };
+
+            let url = url.clone();
+            async move { self.post_json::<_, TokenPricesResponse>(url, &body).await }
+        });
+
+        let responses = stream::iter(requests).buffered(MAX_CONCURRENT_PRICES_REQUESTS).try_collect().await?;
+        Ok(merge_prices(responses))
+    }
+
+    /// Performs request to get prices of all the tokens whitelisted by 1inch.
+    /// Currency `None` means that prices are returned in native Wei of
+    /// blockchain.
+    pub async fn get_whitelisted_tokens_price(
+        &self,
+        currency: Option<client::SupportedCurrencies>,
+    ) -> Result<TokenPricesResponse, OneInchError> {
+        let url = format!("{}/price/{}/{}", self.base_url, SPOT_PRICE_API_VERSION, self.network_id);
 
-        let mut url_with_params = format!("{}{}", base_url, comma_separated_addresses);
+        let mut params = vec![];
+        insert_optional_param(&mut params, "currency", currency.map(|c| c.to_string()));
 
-        // Adding `currency` param if not None
-        if let Some(currency) = details.currency {
-            url_with_params = format!("{}?currency={}", url_with_params, currency);
+        self.get_json(parse_url_with_params(&url, params)?).await
+    }
+}
+
+fn merge_prices(responses: Vec<TokenPricesResponse>) -> TokenPricesResponse {
+    TokenPricesResponse { prices: responses.into_iter().flat_map(|response| response.prices).collect() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::SupportedCurrencies,
+        transport::{testing::*, Mock, MockTransport},
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn test_prices_deserialization() {
+        let json = r#"{"0x55d398326f99059ff775485246999027b3197955":"1.0001","0x1d2f0da169ceb9fc7b3144628db156f3f6c60dbe":"520000000000000000"}"#;
+
+        let response: TokenPricesResponse = serde_json::from_str(json).unwrap();
+        let usdt = "0x55d398326f99059ff775485246999027b3197955".parse().unwrap();
+        assert_eq!(response.prices[&usdt], BigDecimal::from_str("1.0001").unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_prices_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(
+            Mock::get(format!("/price/v1.1/56/{},{}", USDT, XRP))
+                .query_param("currency", "USD")
+                .json_body(format!(r#"{{"{}":"1.0001","{}":"0.52"}}"#, USDT, XRP)),
+        );
+
+        let details = TokensPricesRequestBuilder::new()
+            .addresses(vec![USDT.parse().unwrap(), XRP.parse().unwrap()])
+            .currency(SupportedCurrencies::USD)
+            .build()
+            .unwrap();
+        let prices = client_with(&transport).get_tokens_price(details).await.unwrap();
+        assert_eq!(prices.prices.get(&XRP.parse().unwrap()).unwrap(), &"0.52".parse::<BigDecimal>().unwrap());
+    }
+
+    fn prices_json(addresses: &[Address]) -> String {
+        let entries = addresses.iter().map(|a| format!(r#""{:#x}":"1.5""#, a)).collect::<Vec<_>>().join(",");
+        format!("{{{}}}", entries)
+    }
+
+    fn addresses(count: u16) -> Vec<Address> {
+        (0..count)
+            .map(|i| {
+                let mut bytes = [0u8; 20];
+                bytes[18..].copy_from_slice(&i.to_be_bytes());
+                Address::from_bytes(bytes)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_get_prices_chunking_with_mock() {
+        let addresses = addresses(120);
+
+        let transport = MockTransport::new();
+        for chunk in addresses.chunks(50) {
+            let path = format!(
+                "/price/v1.1/56/{}",
+                chunk.iter().map(|a| format!("{:#x}", a)).collect::<Vec<_>>().join(",")
+            );
+            transport.register(Mock::get(path).json_body(prices_json(chunk)));
         }
 
-        let url = Url::parse(&url_with_params).map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let details = TokensPricesRequestBuilder::new().addresses(addresses).build().unwrap();
+        let prices = client_with(&transport).get_tokens_price(details).await.unwrap();
+        assert_eq!(prices.prices.len(), 120);
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_post_prices_chunking_with_mock() {
+        let addresses = addresses(1500);
 
-        dbg!(&url.clone().to_string());
+        let transport = MockTransport::new();
+        transport.register(Mock::post("/price/v1.1/56").json_body(prices_json(&addresses)));
 
-        let request_result = self.http_client.get(url).header("Authorization", &self.token).send().await;
+        let details = TokensPricesRequestBuilder::new().addresses(addresses.clone()).build().unwrap();
+        let prices = client_with(&transport).post_tokens_price(details).await.unwrap();
+        assert_eq!(prices.prices[&addresses[1499]], "1.5".parse::<BigDecimal>().unwrap());
 
-        let response = request_result
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?
-            .error_for_status()
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let mut chunk_sizes: Vec<usize> = transport
+            .requests()
+            .iter()
+            .map(|request| {
+                let body: serde_json::Value = serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+                assert!(body.get("currency").is_none());
+                body["tokens"].as_array().unwrap().len()
+            })
+            .collect();
+        chunk_sizes.sort_unstable();
+        assert_eq!(chunk_sizes, vec![500, 1000]);
+    }
 
-        let tokens_prices_response: TokenPricesResponse = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
+    #[tokio::test]
+    async fn test_whitelisted_prices_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(
+            Mock::get("/price/v1.1/56").query_param("currency", "USD").json_body(prices_json(&[USDT.parse().unwrap()])),
+        );
 
-        Ok(tokens_prices_response)
+        let prices = client_with(&transport).get_whitelisted_tokens_price(Some(SupportedCurrencies::USD)).await.unwrap();
+        assert_eq!(prices.prices.len(), 1);
     }
 }
@@ -0,0 +1,5 @@
+#[allow(clippy::module_inception)]
+mod traces;
+mod types;
+
+pub use types::*;
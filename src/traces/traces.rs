@@ -0,0 +1,117 @@
+use crate::{
+    client::OneInchClient,
+    common::address::Address,
+    consts::TRACES_API_VERSION,
+    error::OneInchError,
+    traces::{BlockTrace, SyncedInterval, TransactionTrace, TransactionTraceResponse},
+    utils::request::parse_url,
+};
+
+impl OneInchClient {
+    /// Performs request to get the range of blocks traces are available for.
+    pub async fn get_synced_interval(&self) -> Result<SyncedInterval, OneInchError> {
+        let url = format!(
+            "{}/traces/{}/chain/{}/synced-interval",
+            self.base_url, TRACES_API_VERSION, self.network_id
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get traces of all the transactions of the block.
+    pub async fn get_block_trace(&self, block_number: u64) -> Result<BlockTrace, OneInchError> {
+        let url = format!(
+            "{}/traces/{}/chain/{}/block-trace/{}",
+            self.base_url, TRACES_API_VERSION, self.network_id, block_number
+        );
+
+        self.get_json(parse_url(&url)?).await
+    }
+
+    /// Performs request to get trace of the transaction in the block by its
+    /// hash. The API has no lookup by hash alone, the block number comes with
+    /// the transaction receipt or the
+    /// [history event](crate::history::HistoryEventDetails::block_number).
+    pub async fn get_transaction_trace(&self, block_number: u64, tx_hash: &str) -> Result<TransactionTrace, OneInchError> {
+        let url = format!(
+            "{}/traces/{}/chain/{}/block-trace/{}/tx-hash/{}",
+            self.base_url, TRACES_API_VERSION, self.network_id, block_number, tx_hash
+        );
+
+        let response: TransactionTraceResponse = self.get_json(parse_url(&url)?).await?;
+        Ok(response.transaction_trace)
+    }
+
+    /// Performs request to get trace of the transaction by its position in the
+    /// block.
+    pub async fn get_transaction_trace_by_offset(
+        &self,
+        block_number: u64,
+        offset: u32,
+    ) -> Result<TransactionTrace, OneInchError> {
+        let url = format!(
+            "{}/traces/{}/chain/{}/block-trace/{}/offset/{}",
+            self.base_url, TRACES_API_VERSION, self.network_id, block_number, offset
+        );
+
+        let response: TransactionTraceResponse = self.get_json(parse_url(&url)?).await?;
+        Ok(response.transaction_trace)
+    }
+
+    /// Performs request to get trace of the transaction in the block by its
+    /// sender and nonce, e.g. for a transaction which hash is unknown because
+    /// it was replaced. The API has no such lookup, so traces of the whole
+    /// block are requested and searched. Returns `None` if the block has no
+    /// such transaction.
+    pub async fn get_transaction_trace_by_nonce(
+        &self,
+        block_number: u64,
+        from: Address,
+        nonce: u64,
+    ) -> Result<Option<TransactionTrace>, OneInchError> {
+        let block = self.get_block_trace(block_number).await?;
+        Ok(block.find_by_nonce(from, nonce).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transport::{testing::*, Mock, MockTransport};
+
+    #[tokio::test]
+    async fn test_traces_with_mock() {
+        let transport = MockTransport::new();
+        transport.register(Mock::get("/traces/v1.0/chain/56/synced-interval").json_body(r#"{"from":1,"to":35000000}"#)).register(
+            Mock::get("/traces/v1.0/chain/56/block-trace/35000000").json_body(
+                r#"{"type":"BlockTrace","number":35000000,"blockHash":"0xdef","blockTimestamp":"0x65b0a4c0","traces":[]}"#,
+            ),
+        );
+
+        let client = client_with(&transport);
+        let interval = client.get_synced_interval().await.unwrap();
+        let block = client.get_block_trace(interval.to).await.unwrap();
+        assert_eq!(block.block_timestamp, 0x65b0a4c0);
+        assert!(block.traces.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_trace_by_nonce_with_mock() {
+        let trace = |hash: &str, nonce: u64| {
+            format!(
+                r#"{{"txHash":"{hash}","nonce":"{nonce:#x}","from":"{WALLET}","to":"{USDT}","value":"0x0","input":"0x","gasLimit":21000,"gasUsed":21000,"status":"0x1"}}"#
+            )
+        };
+        let transport = MockTransport::new();
+        transport.register(Mock::get("/traces/v1.0/chain/56/block-trace/35000000").json_body(format!(
+            r#"{{"type":"BlockTrace","number":35000000,"blockHash":"0xdef","blockTimestamp":"0x65b0a4c0","traces":[{},{}]}}"#,
+            trace("0x01", 4),
+            trace("0x02", 5)
+        )));
+
+        let client = client_with(&transport);
+        let wallet = WALLET.parse().unwrap();
+        let trace = client.get_transaction_trace_by_nonce(35000000, wallet, 5).await.unwrap().unwrap();
+        assert_eq!(trace.tx_hash, "0x02");
+        assert!(client.get_transaction_trace_by_nonce(35000000, wallet, 6).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,237 @@
+use crate::common::{address::Address, amount::TokenAmount, bytes::Bytes};
+use serde::{de, Deserialize, Deserializer};
+
+/// Range of blocks traces are available for.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncedInterval {
+    pub from: u64,
+    pub to: u64,
+}
+
+/// Kind of the call in the trace.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CallType {
+    Call,
+    DelegateCall,
+    StaticCall,
+    CallCode,
+    Create,
+    Create2,
+    SelfDestruct,
+
+    /// Any call type not known to this crate yet.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Log emitted during the call.
+#[derive(Deserialize, Debug, Clone)]
+pub struct TraceLog {
+    #[serde(alias = "address")]
+    pub contract: Address,
+    pub data: Bytes,
+    pub topics: Vec<Bytes>,
+}
+
+/// Call of the transaction trace, with the calls it made nested.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CallTrace {
+    #[serde(rename = "type")]
+    pub call_type: CallType,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: Option<TokenAmount>,
+
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub gas: u64,
+
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub gas_used: u64,
+    pub input: Bytes,
+    pub output: Option<Bytes>,
+
+    /// Error the call failed with, e.g. `execution reverted`.
+    pub error: Option<String>,
+
+    /// Decoded revert reason, if the call reverted with one.
+    pub revert_reason: Option<String>,
+
+    #[serde(default)]
+    pub calls: Vec<CallTrace>,
+
+    #[serde(default)]
+    pub logs: Vec<TraceLog>,
+}
+
+impl CallTrace {
+    /// Returns true if the call failed.
+    pub fn is_reverted(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Returns the deepest failed call of the tree, which is usually the one
+    /// the revert originates from.
+    pub fn find_revert(&self) -> Option<&CallTrace> {
+        if !self.is_reverted() {
+            return None;
+        }
+
+        self.calls.iter().find_map(CallTrace::find_revert).or(Some(self))
+    }
+}
+
+/// Trace of the transaction.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTrace {
+    pub tx_hash: String,
+
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub nonce: u64,
+    pub from: Address,
+    pub to: Option<Address>,
+    pub value: TokenAmount,
+    pub input: Bytes,
+
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub gas_limit: u64,
+
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub gas_used: u64,
+    pub gas_price: Option<TokenAmount>,
+
+    /// Receipt status, `0x1` for successful transactions.
+    pub status: Option<String>,
+    pub error: Option<String>,
+    pub revert_reason: Option<String>,
+
+    #[serde(default)]
+    pub calls: Vec<CallTrace>,
+
+    #[serde(default)]
+    pub logs: Vec<TraceLog>,
+}
+
+impl TransactionTrace {
+    /// Returns true if the transaction failed.
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some() || matches!(self.status.as_deref(), Some("0x0") | Some("0"))
+    }
+
+    /// Returns the deepest failed call of the transaction, if any.
+    pub fn find_revert(&self) -> Option<&CallTrace> {
+        self.calls.iter().find_map(CallTrace::find_revert)
+    }
+}
+
+/// Traces of all the transactions of the block.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTrace {
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub number: u64,
+    pub block_hash: String,
+
+    #[serde(deserialize_with = "deserialize_quantity")]
+    pub block_timestamp: u64,
+    pub traces: Vec<TransactionTrace>,
+}
+
+impl BlockTrace {
+    /// Returns trace of the transaction sent by `from` with the nonce, if the
+    /// block contains it.
+    pub fn find_by_nonce(&self, from: Address, nonce: u64) -> Option<&TransactionTrace> {
+        self.traces.iter().find(|trace| trace.from == from && trace.nonce == nonce)
+    }
+}
+
+/// Response of the transaction trace endpoints.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTraceResponse {
+    pub transaction_trace: TransactionTrace,
+}
+
+// Quantities come either as JSON numbers or as hex strings.
+fn deserialize_quantity<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawQuantity {
+        String(String),
+        Number(u64),
+    }
+
+    match RawQuantity::deserialize(deserializer)? {
+        RawQuantity::Number(value) => Ok(value),
+        RawQuantity::String(s) => {
+            let parsed = match s.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16),
+                None => s.parse(),
+            };
+            parsed.map_err(|_| de::Error::custom(format!("Invalid quantity {}", s)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_trace_deserialization() {
+        let json = r#"{"transactionTrace": {
+            "txHash": "0xabc",
+            "nonce": "0x5",
+            "from": "0x13961a09bcd42dcc078765286be746d87f20e82e",
+            "to": "0x111111125421ca6dc452d289314280a0f8842a65",
+            "value": "0x0",
+            "input": "0x12aa3caf",
+            "gasLimit": 300000,
+            "gasUsed": "0x1d4c0",
+            "status": "0x0",
+            "error": "execution reverted",
+            "calls": [{
+                "type": "CALL",
+                "from": "0x111111125421ca6dc452d289314280a0f8842a65",
+                "to": "0x55d398326f99059ff775485246999027b3197955",
+                "gas": "0x1000",
+                "gasUsed": "0x800",
+                "input": "0xa9059cbb",
+                "error": "execution reverted",
+                "calls": [{
+                    "type": "STATICCALL",
+                    "from": "0x55d398326f99059ff775485246999027b3197955",
+                    "to": "0x1d2f0da169ceb9fc7b3144628db156f3f6c60dbe",
+                    "gas": 100,
+                    "gasUsed": 50,
+                    "input": "0x70a08231",
+                    "output": "0x",
+                    "error": "execution reverted",
+                    "revertReason": "ERC20: transfer amount exceeds balance"
+                }],
+                "logs": [{
+                    "contract": "0x55d398326f99059ff775485246999027b3197955",
+                    "data": "0x",
+                    "topics": ["0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"]
+                }]
+            }]
+        }}"#;
+
+        let trace = serde_json::from_str::<TransactionTraceResponse>(json).unwrap().transaction_trace;
+        assert_eq!(trace.nonce, 5);
+        assert_eq!(trace.gas_used, 120000);
+        assert!(trace.is_failed());
+        assert_eq!(trace.calls[0].logs[0].topics.len(), 1);
+
+        let revert = trace.find_revert().unwrap();
+        assert_eq!(revert.call_type, CallType::StaticCall);
+        assert_eq!(revert.revert_reason.as_deref(), Some("ERC20: transfer amount exceeds balance"));
+
+        let block = BlockTrace { number: 1, block_hash: "0xdef".into(), block_timestamp: 1, traces: vec![trace.clone()] };
+        assert_eq!(block.find_by_nonce(trace.from, 5).unwrap().tx_hash, "0xabc");
+        assert!(block.find_by_nonce(trace.from, 6).is_none());
+        assert!(block.find_by_nonce(Address::ZERO, 5).is_none());
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{
+    builder_setter,
+    client::SupportedNetworks,
+    common::{address::Address, amount::TokenAmount, bytes::Bytes},
+    gas_price::{GasPriceResponse, GasTier},
+    swap::{approve::ApproveCallData, SwapTranactionData},
+    transaction::{AccessListItem, Eip1559Transaction, LegacyTransaction, Transaction},
+};
+use thiserror::Error;
+
+/// Enumerates potential errors when building `Transaction`.
+#[derive(Error, Debug, Eq, PartialEq)]
+pub enum TransactionBuilderError {
+    /// Indicates a required field is missing its value.
+    #[error("Missing {0}")]
+    MissingField(&'static str),
+
+    /// Indicates both legacy gas price and EIP-1559 fees are set, or access
+    /// list is set for legacy transaction.
+    #[error("Legacy gas price can't be combined with EIP-1559 fields")]
+    ConflictingFees,
+
+    /// Indicates the API returned a value which can't be converted.
+    #[error("Invalid {0}")]
+    InvalidField(&'static str),
+}
+
+/// Builder to create instance of
+/// [`Transaction`](crate::transaction::Transaction).
+///
+/// EIP-1559 transaction is built when EIP-1559 fees are set, legacy one when
+/// gas price is set. The builder can be created from the swap and approve
+/// responses, which already contain recipient, calldata, value and gas price:
+///
+/// ```no_run
+/// # use one_inch::{client::SupportedNetworks, swap::SwapTranactionData, transaction::TransactionBuilder};
+/// # fn f(swap_tx: SwapTranactionData) -> Result<(), Box<dyn std::error::Error>> {
+/// let transaction = TransactionBuilder::try_from(swap_tx)?
+///     .network(SupportedNetworks::BSC)
+///     .nonce(42)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct TransactionBuilder {
+    chain_id: Option<u64>,
+    nonce: Option<u64>,
+    to: Option<Address>,
+    value: Option<TokenAmount>,
+    data: Option<Bytes>,
+    gas_limit: Option<u64>,
+    gas_price: Option<TokenAmount>,
+    max_fee_per_gas: Option<TokenAmount>,
+    max_priority_fee_per_gas: Option<TokenAmount>,
+    access_list: Option<Vec<AccessListItem>>,
+}
+
+impl TransactionBuilder {
+    pub fn new() -> Self {
+        TransactionBuilder::default()
+    }
+
+    builder_setter!(chain_id, u64);
+    builder_setter!(nonce, u64);
+    builder_setter!(to, Address);
+    builder_setter!(value, TokenAmount);
+    builder_setter!(data, Bytes);
+    builder_setter!(gas_limit, u64);
+    builder_setter!(gas_price, TokenAmount);
+    builder_setter!(max_fee_per_gas, TokenAmount);
+    builder_setter!(max_priority_fee_per_gas, TokenAmount);
+    builder_setter!(access_list, Vec<AccessListItem>);
+
+    /// Sets chain id of the network.
+    pub fn network(mut self, network: SupportedNetworks) -> Self {
+        self.chain_id = Some(network.chain_id().into());
+        self
+    }
+
+    /// Sets EIP-1559 fees, dropping the legacy gas price, e.g. the one which
+    /// came with the swap response.
+    pub fn eip1559_fees(mut self, max_fee_per_gas: TokenAmount, max_priority_fee_per_gas: TokenAmount) -> Self {
+        self.gas_price = None;
+        self.max_fee_per_gas = Some(max_fee_per_gas);
+        self.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+        self
+    }
+
+    /// Sets fees of the tier from
+    /// [`get_gas_price`](crate::client::OneInchClient::get_gas_price) response:
+    /// EIP-1559 fees if the network supports them, legacy gas price otherwise.
+    pub fn gas_price_tier(self, gas_price: &GasPriceResponse, tier: GasTier) -> Self {
+        match gas_price.eip1559_fee(tier) {
+            Some(fee) => self.eip1559_fees(fee.max_fee_per_gas.clone(), fee.max_priority_fee_per_gas.clone()),
+            None => self.gas_price(gas_price.gas_price(tier)),
+        }
+    }
+
+    /// Attempts to construct a [`Transaction`](crate::transaction::Transaction)
+    /// from the builder, returning errors if required fields are missing or if
+    /// legacy and EIP-1559 fields are mixed.
+    pub fn build(self) -> Result<Transaction, TransactionBuilderError> {
+        let chain_id = self.chain_id.ok_or(TransactionBuilderError::MissingField("chain_id"))?;
+        let nonce = self.nonce.ok_or(TransactionBuilderError::MissingField("nonce"))?;
+        let gas_limit = self.gas_limit.ok_or(TransactionBuilderError::MissingField("gas_limit"))?;
+        let value = self.value.unwrap_or_default();
+        let data = self.data.unwrap_or_default();
+
+        let is_eip1559 = self.max_fee_per_gas.is_some() || self.max_priority_fee_per_gas.is_some();
+
+        match (self.gas_price, is_eip1559) {
+            (Some(_), true) => Err(TransactionBuilderError::ConflictingFees),
+            (Some(_), false) if self.access_list.is_some() => Err(TransactionBuilderError::ConflictingFees),
+            (Some(gas_price), false) => Ok(Transaction::Legacy(LegacyTransaction {
+                chain_id: Some(chain_id),
+                nonce,
+                gas_price,
+                gas_limit,
+                to: self.to,
+                value,
+                data,
+            })),
+            (None, true) => Ok(Transaction::Eip1559(Eip1559Transaction {
+                chain_id,
+                nonce,
+                max_priority_fee_per_gas: self
+                    .max_priority_fee_per_gas
+                    .ok_or(TransactionBuilderError::MissingField("max_priority_fee_per_gas"))?,
+                max_fee_per_gas: self.max_fee_per_gas.ok_or(TransactionBuilderError::MissingField("max_fee_per_gas"))?,
+                gas_limit,
+                to: self.to,
+                value,
+                data,
+                access_list: self.access_list.unwrap_or_default(),
+            })),
+            (None, false) => Err(TransactionBuilderError::MissingField("gas_price")),
+        }
+    }
+}
+
+impl TryFrom<SwapTranactionData> for TransactionBuilder {
+    type Error = TransactionBuilderError;
+
+    /// Prefills recipient, calldata, value, gas price and gas limit from the
+    /// swap response.
+    fn try_from(tx: SwapTranactionData) -> Result<Self, Self::Error> {
+        Ok(TransactionBuilder::new()
+            .to(tx.to)
+            .value(tx.value)
+            .data(tx.data.parse().map_err(|_| TransactionBuilderError::InvalidField("data"))?)
+            .gas_price(tx.gas_price.parse().map_err(|_| TransactionBuilderError::InvalidField("gas_price"))?)
+            .gas_limit(u64::try_from(tx.gas).map_err(|_| TransactionBuilderError::InvalidField("gas"))?))
+    }
+}
+
+impl TryFrom<ApproveCallData> for TransactionBuilder {
+    type Error = TransactionBuilderError;
+
+    /// Prefills recipient, calldata, value and gas price from the approve
+    /// response. The API doesn't estimate gas for approvals, so gas limit
+    /// has to be set separately.
+    fn try_from(tx: ApproveCallData) -> Result<Self, Self::Error> {
+        Ok(TransactionBuilder::new()
+            .to(tx.to)
+            .value(tx.value)
+            .data(tx.data.parse().map_err(|_| TransactionBuilderError::InvalidField("data"))?)
+            .gas_price(tx.gas_price.parse().map_err(|_| TransactionBuilderError::InvalidField("gas_price"))?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approve_call_data() -> ApproveCallData {
+        serde_json::from_str(
+            r#"{"data":"0x095ea7b3","gasPrice":"3000000000","to":"0x55d398326f99059ff775485246999027b3197955","value":"0"}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_transaction_from_approve() {
+        let builder = TransactionBuilder::try_from(approve_call_data()).unwrap().network(SupportedNetworks::BSC).nonce(1);
+        assert_eq!(builder.build().err(), Some(TransactionBuilderError::MissingField("gas_limit")));
+
+        let tx = TransactionBuilder::try_from(approve_call_data())
+            .unwrap()
+            .network(SupportedNetworks::BSC)
+            .nonce(1)
+            .gas_limit(50000)
+            .build()
+            .unwrap();
+
+        match tx {
+            Transaction::Legacy(tx) => {
+                assert_eq!(tx.chain_id, Some(56));
+                assert_eq!(tx.gas_price, 3_000_000_000u64.into());
+                assert_eq!(tx.data.to_string(), "0x095ea7b3");
+            }
+            _ => panic!("Expected legacy transaction"),
+        }
+    }
+
+    #[test]
+    fn test_transaction_from_swap() {
+        let swap_tx: SwapTranactionData = serde_json::from_str(
+            r#"{"from":"0x13961a09bcd42dcc078765286be746d87f20e82e","to":"0x111111125421ca6dc452d289314280a0f8842a65","data":"0x12aa3caf","value":"1000","gasPrice":"3000000000","gas":200000}"#,
+        )
+        .unwrap();
+
+        let builder = TransactionBuilder::try_from(swap_tx).unwrap().chain_id(1).nonce(7);
+        let tx = builder.eip1559_fees(30_000_000_000u64.into(), 1_000_000_000u64.into()).build().unwrap();
+
+        match tx {
+            Transaction::Eip1559(tx) => {
+                assert_eq!(tx.gas_limit, 200000);
+                assert_eq!(tx.value, 1000u64.into());
+                assert_eq!(tx.max_fee_per_gas, 30_000_000_000u64.into());
+            }
+            _ => panic!("Expected EIP-1559 transaction"),
+        }
+
+        let error = TransactionBuilder::new()
+            .chain_id(1)
+            .nonce(0)
+            .gas_limit(21000)
+            .gas_price(1u64.into())
+            .max_fee_per_gas(1u64.into())
+            .build()
+            .unwrap_err();
+        assert_eq!(error, TransactionBuilderError::ConflictingFees);
+    }
+
+    #[test]
+    fn test_gas_price_tier() {
+        let legacy: GasPriceResponse =
+            serde_json::from_str(r#"{"low":"1","medium":"2","high":"5000000000","instant":"4"}"#).unwrap();
+        let builder = TransactionBuilder::try_from(approve_call_data()).unwrap().chain_id(56).nonce(0).gas_limit(50000);
+
+        match builder.gas_price_tier(&legacy, GasTier::High).build().unwrap() {
+            Transaction::Legacy(tx) => assert_eq!(tx.gas_price, 5_000_000_000u64.into()),
+            _ => panic!("Expected legacy transaction"),
+        }
+
+        let eip1559: GasPriceResponse = serde_json::from_str(
+            r#"{"baseFee":"1","low":{"maxPriorityFeePerGas":"1","maxFeePerGas":"2"},"medium":{"maxPriorityFeePerGas":"3","maxFeePerGas":"4"},"high":{"maxPriorityFeePerGas":"5","maxFeePerGas":"6"},"instant":{"maxPriorityFeePerGas":"7","maxFeePerGas":"8"}}"#,
+        )
+        .unwrap();
+        let builder = TransactionBuilder::try_from(approve_call_data()).unwrap().chain_id(1).nonce(0).gas_limit(50000);
+
+        match builder.gas_price_tier(&eip1559, GasTier::Medium).build().unwrap() {
+            Transaction::Eip1559(tx) => {
+                assert_eq!(tx.max_fee_per_gas, 4u64.into());
+                assert_eq!(tx.max_priority_fee_per_gas, 3u64.into());
+            }
+            _ => panic!("Expected EIP-1559 transaction"),
+        }
+    }
+}
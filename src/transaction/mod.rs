@@ -0,0 +1,5 @@
+mod builder;
+mod types;
+
+pub use builder::*;
+pub use types::*;
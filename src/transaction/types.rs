@@ -0,0 +1,236 @@
+use crate::{
+    common::{address::Address, amount::TokenAmount, bytes::Bytes},
+    signer::{Signature, Signer, SignerError},
+    utils::{keccak::keccak256, rlp},
+};
+
+// Type byte of EIP-1559 transactions, see EIP-2718.
+const EIP1559_TX_TYPE: u8 = 0x02;
+
+/// Pre EIP-1559 transaction paying fixed gas price, signed with
+/// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) replay protection when
+/// chain id is set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyTransaction {
+    pub chain_id: Option<u64>,
+    pub nonce: u64,
+    pub gas_price: TokenAmount,
+    pub gas_limit: u64,
+
+    /// Recipient of the transaction, `None` for contract deployment.
+    pub to: Option<Address>,
+    pub value: TokenAmount,
+    pub data: Bytes,
+}
+
+/// Storage slots of the contract the transaction is going to access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessListItem {
+    pub address: Address,
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+/// [EIP-1559](https://eips.ethereum.org/EIPS/eip-1559) transaction paying
+/// base fee plus priority fee.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip1559Transaction {
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub max_priority_fee_per_gas: TokenAmount,
+    pub max_fee_per_gas: TokenAmount,
+    pub gas_limit: u64,
+
+    /// Recipient of the transaction, `None` for contract deployment.
+    pub to: Option<Address>,
+    pub value: TokenAmount,
+    pub data: Bytes,
+    pub access_list: Vec<AccessListItem>,
+}
+
+/// Transaction which can be signed and broadcasted with
+/// `eth_sendRawTransaction`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transaction {
+    Legacy(LegacyTransaction),
+    Eip1559(Eip1559Transaction),
+}
+
+impl Transaction {
+    /// Computes the hash which is signed by the sender.
+    pub fn signing_hash(&self) -> [u8; 32] {
+        keccak256(self.encode_unsigned())
+    }
+
+    /// Encodes the transaction without signature, as it's hashed for signing.
+    pub fn encode_unsigned(&self) -> Bytes {
+        match self {
+            Transaction::Legacy(tx) => {
+                let mut fields = tx.fields();
+                if let Some(chain_id) = tx.chain_id {
+                    fields.extend([rlp::encode_uint(&chain_id.to_be_bytes()), rlp::encode_uint(&[]), rlp::encode_uint(&[])]);
+                }
+                rlp::encode_list(&fields).into()
+            }
+            Transaction::Eip1559(tx) => typed(EIP1559_TX_TYPE, rlp::encode_list(&tx.fields())),
+        }
+    }
+
+    /// Encodes the transaction with the signature, the result can be sent with
+    /// `eth_sendRawTransaction`.
+    pub fn encode_signed(&self, signature: &Signature) -> Bytes {
+        let signature_fields =
+            |v: u64| [rlp::encode_uint(&v.to_be_bytes()), rlp::encode_uint(&signature.r), rlp::encode_uint(&signature.s)];
+
+        match self {
+            Transaction::Legacy(tx) => {
+                let v = match tx.chain_id {
+                    Some(chain_id) => chain_id * 2 + 35 + signature.y_parity as u64,
+                    None => signature.v().into(),
+                };
+
+                let mut fields = tx.fields();
+                fields.extend(signature_fields(v));
+                rlp::encode_list(&fields).into()
+            }
+            Transaction::Eip1559(tx) => {
+                let mut fields = tx.fields();
+                fields.extend(signature_fields(signature.y_parity as u64));
+                typed(EIP1559_TX_TYPE, rlp::encode_list(&fields))
+            }
+        }
+    }
+
+    /// Signs the transaction returning signed raw transaction.
+    pub fn sign<S: Signer>(&self, signer: &S) -> Result<Bytes, SignerError> {
+        Ok(self.encode_signed(&signer.sign_transaction(self)?))
+    }
+}
+
+impl LegacyTransaction {
+    fn fields(&self) -> Vec<Vec<u8>> {
+        vec![
+            rlp::encode_uint(&self.nonce.to_be_bytes()),
+            rlp::encode_uint(&self.gas_price.to_be_bytes()),
+            rlp::encode_uint(&self.gas_limit.to_be_bytes()),
+            encode_to(&self.to),
+            rlp::encode_uint(&self.value.to_be_bytes()),
+            rlp::encode_bytes(&self.data),
+        ]
+    }
+}
+
+impl Eip1559Transaction {
+    fn fields(&self) -> Vec<Vec<u8>> {
+        let access_list = self
+            .access_list
+            .iter()
+            .map(|item| {
+                let keys = item.storage_keys.iter().map(|key| rlp::encode_bytes(key)).collect::<Vec<_>>();
+                rlp::encode_list(&[rlp::encode_bytes(item.address.as_bytes()), rlp::encode_list(&keys)])
+            })
+            .collect::<Vec<_>>();
+
+        vec![
+            rlp::encode_uint(&self.chain_id.to_be_bytes()),
+            rlp::encode_uint(&self.nonce.to_be_bytes()),
+            rlp::encode_uint(&self.max_priority_fee_per_gas.to_be_bytes()),
+            rlp::encode_uint(&self.max_fee_per_gas.to_be_bytes()),
+            rlp::encode_uint(&self.gas_limit.to_be_bytes()),
+            encode_to(&self.to),
+            rlp::encode_uint(&self.value.to_be_bytes()),
+            rlp::encode_bytes(&self.data),
+            rlp::encode_list(&access_list),
+        ]
+    }
+}
+
+impl From<LegacyTransaction> for Transaction {
+    fn from(tx: LegacyTransaction) -> Self {
+        Transaction::Legacy(tx)
+    }
+}
+
+impl From<Eip1559Transaction> for Transaction {
+    fn from(tx: Eip1559Transaction) -> Self {
+        Transaction::Eip1559(tx)
+    }
+}
+
+fn encode_to(to: &Option<Address>) -> Vec<u8> {
+    rlp::encode_bytes(to.as_ref().map(|to| &to.as_bytes()[..]).unwrap_or_default())
+}
+
+// Prefixes encoded payload with the EIP-2718 transaction type.
+fn typed(tx_type: u8, payload: Vec<u8>) -> Bytes {
+    let mut encoded = vec![tx_type];
+    encoded.extend(payload);
+    encoded.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::LocalSigner;
+
+    fn signer() -> LocalSigner {
+        LocalSigner::from_bytes(&[0x46; 32]).unwrap()
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_eip155_example() {
+        // Example from the EIP-155 specification.
+        let tx = Transaction::Legacy(LegacyTransaction {
+            chain_id: Some(1),
+            nonce: 9,
+            gas_price: 20_000_000_000u64.into(),
+            gas_limit: 21000,
+            to: Some(Address::from_bytes([0x35; 20])),
+            value: 1_000_000_000_000_000_000u64.into(),
+            data: Bytes::new(),
+        });
+
+        assert_eq!(
+            tx.encode_unsigned().to_string(),
+            "0xec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080"
+        );
+        assert_eq!(
+            hex(&tx.signing_hash()),
+            "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53"
+        );
+        assert_eq!(
+            tx.sign(&signer()).unwrap().to_string(),
+            "0xf86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+    }
+
+    #[test]
+    fn test_eip1559_encoding() {
+        let tx = Transaction::Eip1559(Eip1559Transaction {
+            chain_id: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: 1_000_000_000u64.into(),
+            max_fee_per_gas: 30_000_000_000u64.into(),
+            gas_limit: 21000,
+            to: Some(Address::from_bytes([0x35; 20])),
+            value: 1u64.into(),
+            data: Bytes::new(),
+            access_list: vec![],
+        });
+
+        assert_eq!(
+            tx.encode_unsigned().to_string(),
+            format!("0x02e80180843b9aca008506fc23ac0082520894{}0180c0", "35".repeat(20))
+        );
+
+        let signer = signer();
+        let signature = signer.sign_transaction(&tx).unwrap();
+        assert_eq!(signature.recover(&tx.signing_hash()).unwrap(), signer.address());
+
+        let signed = tx.encode_signed(&signature);
+        assert_eq!(signed[..2], [0x02, 0xf8]);
+    }
+}
@@ -0,0 +1,283 @@
+use crate::{
+    error::OneInchError,
+    transport::{HttpRequest, HttpResponse, Transport},
+};
+use async_trait::async_trait;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+// Value written instead of sensitive headers.
+const REDACTED: &str = "<redacted>";
+
+// Headers redacted in both requests and responses, along with any header
+// which name contains one of `SENSITIVE_HEADER_PARTS`.
+const SENSITIVE_HEADERS: [&str; 4] = ["authorization", "proxy-authorization", "cookie", "set-cookie"];
+const SENSITIVE_HEADER_PARTS: [&str; 6] = ["auth", "token", "session", "secret", "api-key", "apikey"];
+
+/// [`Transport`] wrapper saving every request/response pair passing through
+/// the inner transport into a fixture directory.
+///
+/// Each interaction is stored as a separate JSON file named after the
+/// normalized request (method, path, sorted query params and body), so the
+/// directory can be served back with [`ReplayTransport`]. Values of the
+/// credential and session headers, like `Authorization`, `Cookie` or
+/// `Set-Cookie`, are never written to disk, neither for requests nor for
+/// responses.
+#[derive(Debug, Clone)]
+pub struct RecordingTransport<T: Transport> {
+    inner: T,
+    directory: PathBuf,
+}
+
+/// [`Transport`] serving responses previously saved by
+/// [`RecordingTransport`] without touching the network.
+///
+/// Requests are matched by their normalized form, so the order of query
+/// params doesn't matter. Requests without a fixture fail with
+/// [`OneInchError::Transport`].
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    directory: PathBuf,
+}
+
+/// Single recorded interaction as it's stored on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// Request part of a [`Fixture`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: BTreeMap<String, String>,
+    pub body: Option<String>,
+}
+
+/// Response part of a [`Fixture`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    /// Creates a transport recording interactions of `inner` into
+    /// `directory`. The directory is created on the first write if needed.
+    pub fn new(inner: T, directory: impl Into<PathBuf>) -> Self {
+        RecordingTransport { inner, directory: directory.into() }
+    }
+}
+
+#[async_trait]
+impl<T: Transport> Transport for RecordingTransport<T> {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, OneInchError> {
+        let recorded_request = RecordedRequest::from_request(&request);
+        let response = self.inner.send(request).await?;
+
+        let fixture = Fixture {
+            response: RecordedResponse {
+                status: response.status.as_u16(),
+                headers: headers_to_map(&response.headers),
+                body: String::from_utf8_lossy(&response.body).into_owned(),
+            },
+            request: recorded_request,
+        };
+
+        let content = serde_json::to_vec_pretty(&fixture)
+            .map_err(|e| OneInchError::Transport(format!("Failed to serialize fixture: {}", e)))?;
+
+        tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(|e| OneInchError::Transport(format!("Failed to create {}: {}", self.directory.display(), e)))?;
+
+        let path = fixture_path(&self.directory, &fixture.request);
+        tokio::fs::write(&path, content)
+            .await
+            .map_err(|e| OneInchError::Transport(format!("Failed to write {}: {}", path.display(), e)))?;
+
+        Ok(response)
+    }
+}
+
+impl ReplayTransport {
+    /// Creates a transport serving fixtures from `directory`.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        ReplayTransport { directory: directory.into() }
+    }
+}
+
+#[async_trait]
+impl Transport for ReplayTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, OneInchError> {
+        let path = fixture_path(&self.directory, &RecordedRequest::from_request(&request));
+
+        let content = tokio::fs::read(&path).await.map_err(|e| {
+            OneInchError::Transport(format!("No fixture for {} {} ({}): {}", request.method, request.url, path.display(), e))
+        })?;
+
+        let fixture: Fixture = serde_json::from_slice(&content)
+            .map_err(|e| OneInchError::Transport(format!("Invalid fixture {}: {}", path.display(), e)))?;
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in fixture.response.headers {
+            if let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::try_from(value)) {
+                headers.insert(name, value);
+            }
+        }
+
+        Ok(HttpResponse {
+            status: StatusCode::from_u16(fixture.response.status)
+                .map_err(|_| OneInchError::Transport(format!("Invalid status in fixture {}", path.display())))?,
+            headers,
+            body: fixture.response.body.into_bytes(),
+        })
+    }
+}
+
+impl RecordedRequest {
+    // Builds normalized representation of the request with sorted query
+    // params and sensitive headers redacted.
+    fn from_request(request: &HttpRequest) -> Self {
+        let mut query: Vec<(String, String)> = request.url.query_pairs().map(|(k, v)| (k.into_owned(), v.into_owned())).collect();
+        query.sort();
+
+        let headers = headers_to_map(&request.headers);
+
+        RecordedRequest {
+            method: request.method.to_string(),
+            path: request.url.path().trim_end_matches('/').to_string(),
+            query,
+            headers,
+            body: request.body.as_ref().map(|body| String::from_utf8_lossy(body).into_owned()),
+        }
+    }
+
+    /// Returns the key the request is matched by. Headers are not a part of
+    /// the key.
+    pub fn key(&self) -> String {
+        let query = self.query.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<String>>().join("&");
+
+        format!("{} {}?{} {}", self.method, self.path, query, self.body.as_deref().unwrap_or_default())
+    }
+}
+
+// Converts headers into a sorted map with sensitive values redacted, skipping
+// non-textual values.
+fn headers_to_map(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = if is_sensitive_header(name.as_str()) { REDACTED } else { value.to_str().ok()? };
+            Some((name.as_str().to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+// Header names are lowercase in `HeaderMap`.
+fn is_sensitive_header(name: &str) -> bool {
+    SENSITIVE_HEADERS.contains(&name) || SENSITIVE_HEADER_PARTS.iter().any(|part| name.contains(part))
+}
+
+// Returns the path of the fixture file for the request. The name consists of
+// readable method and path followed by a hash of the full key to keep names
+// unique and short.
+fn fixture_path(directory: &Path, request: &RecordedRequest) -> PathBuf {
+    let readable: String = format!("{}{}", request.method, request.path)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' })
+        .take(100)
+        .collect();
+
+    directory.join(format!("{}_{:016x}.json", readable, fnv1a(request.key().as_bytes())))
+}
+
+// FNV-1a hash, used because it's stable between runs and Rust versions unlike
+// the std `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{OneInchClient, SupportedNetworks},
+        swap::approve::AllowanceDetailsBuilder,
+        transport::{Mock, MockTransport},
+    };
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let directory = std::env::temp_dir().join(format!("one_inch_fixtures_{}", std::process::id()));
+
+        let mock = MockTransport::new();
+        mock.register(
+            Mock::get("/swap/v5.2/1/approve/allowance")
+                .header("Set-Cookie", "session=secret-cookie")
+                .header("X-Session-Id", "secret-session")
+                .header("Retry-After", "1")
+                .json_body(r#"{"allowance":"115792"}"#),
+        );
+
+        let details = AllowanceDetailsBuilder::new()
+            .token_address("0xdac17f958d2ee523a2206206994597c13d831ec7".parse().unwrap())
+            .wallet_address("0x13961a09bcd42dcc078765286be746d87f20e82e".parse().unwrap())
+            .build()
+            .unwrap();
+
+        let recording_client = OneInchClient::builder()
+            .token("secret-token".to_string())
+            .network_id(SupportedNetworks::Ethereum)
+            .transport(RecordingTransport::new(mock, &directory))
+            .build()
+            .unwrap();
+        let recorded = recording_client.get_allowance(details.clone()).await.unwrap();
+
+        // Credentials of both the request and the response never reach the
+        // disk, while the other headers are kept
+        for entry in std::fs::read_dir(&directory).unwrap() {
+            let content = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+            assert!(!content.contains("secret"));
+
+            let fixture: Fixture = serde_json::from_str(&content).unwrap();
+            assert_eq!(fixture.request.headers["authorization"], REDACTED);
+            assert_eq!(fixture.response.headers["set-cookie"], REDACTED);
+            assert_eq!(fixture.response.headers["x-session-id"], REDACTED);
+            assert_eq!(fixture.response.headers["retry-after"], "1");
+        }
+
+        let replay_client = OneInchClient::builder()
+            .token("another-token".to_string())
+            .network_id(SupportedNetworks::Ethereum)
+            .transport(ReplayTransport::new(&directory))
+            .build()
+            .unwrap();
+        let replayed = replay_client.get_allowance(details).await.unwrap();
+        assert_eq!(recorded.allowance, replayed.allowance);
+
+        let missing = replay_client.get_tokens_list().await.unwrap_err();
+        assert!(matches!(missing, OneInchError::Transport(_)));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_key_ignores_query_order() {
+        let first = HttpRequest::new(reqwest::Method::GET, "https://api.1inch.dev/swap/quote/?b=2&a=1".parse().unwrap());
+        let second = HttpRequest::new(reqwest::Method::GET, "https://api.1inch.dev/swap/quote?a=1&b=2".parse().unwrap());
+
+        assert_eq!(RecordedRequest::from_request(&first).key(), RecordedRequest::from_request(&second).key());
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{
+    error::OneInchError,
+    transport::{HttpRequest, HttpResponse, Transport},
+};
+use async_trait::async_trait;
+
+/// Default [`Transport`] sending requests over the network with
+/// `reqwest::Client`.
+///
+/// reqwest::Client does not need to be Rc/Arc because it already uses an Arc
+/// internally.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    http_client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport using the specified `reqwest::Client`.
+    pub fn new(http_client: reqwest::Client) -> Self {
+        ReqwestTransport { http_client }
+    }
+
+    /// Returns the underlying `reqwest::Client`.
+    pub fn http_client(&self) -> &reqwest::Client {
+        &self.http_client
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, OneInchError> {
+        let mut builder = self.http_client.request(request.method, request.url).headers(request.headers);
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().await.map_err(OneInchError::Network)?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(OneInchError::Network)?.to_vec();
+
+        Ok(HttpResponse { status, headers, body })
+    }
+}
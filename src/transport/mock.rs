@@ -0,0 +1,168 @@
+use crate::{
+    error::OneInchError,
+    transport::{HttpRequest, HttpResponse, Transport},
+};
+use async_trait::async_trait;
+use reqwest::{
+    header::{HeaderName, HeaderValue},
+    Method, StatusCode,
+};
+use std::sync::{Arc, Mutex};
+
+/// In-memory [`Transport`] returning registered fixtures instead of sending
+/// requests over the network.
+///
+/// Requests are matched against registered [`Mock`]s in registration order.
+/// The transport is cheap to clone and all the clones share registered mocks
+/// and received requests, so a clone can be passed to the client while the
+/// original one is used for assertions.
+///
+/// ```
+/// use one_inch::{
+///     client::{OneInchClient, SupportedNetworks},
+///     transport::{Mock, MockTransport},
+/// };
+///
+/// let transport = MockTransport::new();
+/// transport.register(Mock::get("/swap/v5.2/56/quote").query_param("amount", "100").json_body(r#"{"toAmount":"99"}"#));
+///
+/// let client = OneInchClient::builder()
+///     .token("token".to_string())
+///     .network_id(SupportedNetworks::BSC)
+///     .transport(transport.clone())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    mocks: Arc<Mutex<Vec<Mock>>>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+/// Describes a request to match and the response to return for it.
+#[derive(Debug, Clone)]
+pub struct Mock {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    remaining: Option<usize>,
+    response: HttpResponse,
+}
+
+impl MockTransport {
+    /// Creates a transport without registered mocks.
+    pub fn new() -> Self {
+        MockTransport::default()
+    }
+
+    /// Registers a mock. Mocks registered earlier take precedence.
+    pub fn register(&self, mock: Mock) -> &Self {
+        self.mocks.lock().unwrap_or_else(|e| e.into_inner()).push(mock);
+        self
+    }
+
+    /// Returns all the requests received by the transport so far.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, OneInchError> {
+        self.requests.lock().unwrap_or_else(|e| e.into_inner()).push(request.clone());
+
+        let mut mocks = self.mocks.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mock = mocks
+            .iter_mut()
+            .find(|mock| mock.remaining != Some(0) && mock.matches(&request))
+            .ok_or_else(|| OneInchError::Transport(format!("No mock matches {} {}", request.method, request.url)))?;
+
+        if let Some(remaining) = mock.remaining.as_mut() {
+            *remaining -= 1;
+        }
+
+        Ok(mock.response.clone())
+    }
+}
+
+impl Mock {
+    /// Creates a mock for requests with specified method and url path. By
+    /// default it responds with `200 OK` and an empty JSON object.
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Mock { method, path: path.into(), query: Vec::new(), remaining: None, response: HttpResponse::new(StatusCode::OK, "{}") }
+    }
+
+    /// Creates a mock for GET requests with specified url path.
+    pub fn get(path: impl Into<String>) -> Self {
+        Mock::new(Method::GET, path)
+    }
+
+    /// Creates a mock for POST requests with specified url path.
+    pub fn post(path: impl Into<String>) -> Self {
+        Mock::new(Method::POST, path)
+    }
+
+    /// Requires the request to contain the query param with specified value.
+    /// Params that are not required are ignored while matching.
+    pub fn query_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the status code of the response.
+    pub fn status(mut self, status: u16) -> Self {
+        self.response.status = StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        self
+    }
+
+    /// Adds a header to the response.
+    ///
+    /// # Panics
+    ///
+    /// Panics if name or value are not valid header name and value.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        let name = HeaderName::try_from(name).expect("Invalid header name");
+        let value = HeaderValue::try_from(value).expect("Invalid header value");
+        self.response.headers.insert(name, value);
+        self
+    }
+
+    /// Sets the JSON body of the response, e.g. a fixture loaded with
+    /// `include_str!`.
+    pub fn json_body(mut self, body: impl Into<String>) -> Self {
+        self.response.body = body.into().into_bytes();
+        self.response.headers.insert(reqwest::header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        self
+    }
+
+    /// Limits the amount of requests the mock responds to, after which the
+    /// next matching mock is used.
+    pub fn times(mut self, times: usize) -> Self {
+        self.remaining = Some(times);
+        self
+    }
+
+    fn matches(&self, request: &HttpRequest) -> bool {
+        if self.method != request.method || self.path.trim_end_matches('/') != request.url.path().trim_end_matches('/') {
+            return false;
+        }
+
+        self.query.iter().all(|(name, value)| request.url.query_pairs().any(|(n, v)| n == name.as_str() && v == value.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::testing::client_with;
+
+    #[tokio::test]
+    async fn test_unmatched_request() {
+        let transport = MockTransport::new();
+
+        let error = client_with(&transport).get_liquidity_sources().await.unwrap_err();
+        assert!(matches!(error, OneInchError::Transport(_)));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::error::OneInchError;
+use async_trait::async_trait;
+use reqwest::{header::HeaderMap, Method, StatusCode, Url};
+use std::fmt::Debug;
+
+mod fixtures;
+mod http;
+mod mock;
+#[cfg(test)]
+pub(crate) mod testing;
+
+pub use fixtures::*;
+pub use http::*;
+pub use mock::*;
+
+/// Abstraction over the way requests reach the 1inch API.
+///
+/// [`OneInchClient`](crate::client::OneInchClient) builds the requests, applies
+/// the retry policy and the rate limiter, and parses the responses, while the
+/// transport only moves bytes. [`ReqwestTransport`] is used by default,
+/// [`MockTransport`] serves canned responses for offline testing, while
+/// [`RecordingTransport`] and [`ReplayTransport`] capture real traffic into
+/// fixtures and serve it back.
+#[async_trait]
+pub trait Transport: Debug + Send + Sync {
+    /// Sends the request and returns the raw response. Non-successful status
+    /// codes must be returned as a response, not as an error.
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, OneInchError>;
+}
+
+/// Raw request produced by the client.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+/// Raw response returned by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// Creates a request without headers and body.
+    pub fn new(method: Method, url: Url) -> Self {
+        HttpRequest { method, url, headers: HeaderMap::new(), body: None }
+    }
+}
+
+impl HttpResponse {
+    /// Creates a response with the specified status code and body.
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        HttpResponse { status, headers: HeaderMap::new(), body: body.into() }
+    }
+}
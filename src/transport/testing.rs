@@ -0,0 +1,21 @@
+//! Fixtures shared by the tests of the endpoints, served through
+//! [`MockTransport`].
+
+use crate::{
+    client::{OneInchClient, SupportedNetworks},
+    transport::MockTransport,
+};
+
+pub const USDT: &str = "0x55d398326f99059ff775485246999027b3197955";
+pub const XRP: &str = "0x1d2f0da169ceb9fc7b3144628db156f3f6c60dbe";
+pub const WALLET: &str = "0x13961a09bcd42dcc078765286be746d87f20e82e";
+
+/// Builds the client for BSC sending its requests through the transport.
+pub fn client_with(transport: &MockTransport) -> OneInchClient {
+    OneInchClient::builder()
+        .token("test-token".to_string())
+        .network_id(SupportedNetworks::BSC)
+        .transport(transport.clone())
+        .build()
+        .unwrap()
+}
@@ -12,6 +12,24 @@ macro_rules! builder_setter {
     };
 }
 
+// Macro to generate the setter of the `gas_price` field from a tier of the
+// gas price API response, for builders of the swap API requests.
+#[macro_export]
+macro_rules! gas_price_tier_setter {
+    () => {
+        /// Sets the gas price of the tier from
+        /// [`get_gas_price`](crate::client::OneInchClient::get_gas_price) response.
+        ///
+        /// The swap API only takes a legacy `gasPrice`, so on EIP-1559 networks
+        /// it's the expected price of the tier, the base fee plus the priority
+        /// fee, as [`GasPriceResponse::gas_price`](crate::gas_price::GasPriceResponse::gas_price)
+        /// returns it, rather than the max fee per gas.
+        pub fn gas_price_tier(self, gas_price: &$crate::gas_price::GasPriceResponse, tier: $crate::gas_price::GasTier) -> Self {
+            self.gas_price(gas_price.gas_price(tier).to_string())
+        }
+    };
+}
+
 /// Basic structure for error handling in Builder for structures where there is
 /// no need to handle other errors.
 #[derive(Error, Debug, Eq, PartialEq)]
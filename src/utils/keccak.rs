@@ -0,0 +1,27 @@
+use tiny_keccak::{Hasher, Keccak};
+
+// Computes Keccak-256 hash of the data, as it's used in Ethereum.
+pub(crate) fn keccak256(data: impl AsRef<[u8]>) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+
+    hasher.update(data.as_ref());
+    hasher.finalize(&mut output);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keccak256_empty_input() {
+        let expected = [
+            0xc5, 0xd2, 0x46, 0x01, 0x86, 0xf7, 0x23, 0x3c, 0x92, 0x7e, 0x7d, 0xb2, 0xdc, 0xc7, 0x03, 0xc0, 0xe5, 0x00, 0xb6,
+            0x53, 0xca, 0x82, 0x27, 0x3b, 0x7b, 0xfa, 0xd8, 0x04, 0x5d, 0x85, 0xa4, 0x70,
+        ];
+
+        assert_eq!(keccak256([]), expected);
+    }
+}
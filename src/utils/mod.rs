@@ -1,2 +1,5 @@
 pub mod builder;
+pub mod keccak;
 pub mod params;
+pub mod request;
+pub mod rlp;
@@ -0,0 +1,151 @@
+use crate::{
+    client::OneInchClient,
+    error::{ApiErrorBody, OneInchError},
+    transport::{HttpRequest, HttpResponse},
+};
+use reqwest::{
+    header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER},
+    Method, StatusCode, Url,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+
+impl OneInchClient {
+    /// Performs authorized GET request to the specified url and deserializes
+    /// the response body into `T`.
+    pub(crate) async fn get_json<T: DeserializeOwned>(&self, url: Url) -> Result<T, OneInchError> {
+        self.execute(self.authorized_request(Method::GET, url)?).await
+    }
+
+    /// Performs authorized POST request with `body` serialized as JSON and
+    /// deserializes the response body into `T`.
+    pub(crate) async fn post_json<B: Serialize, T: DeserializeOwned>(&self, url: Url, body: &B) -> Result<T, OneInchError> {
+        let mut request = self.authorized_request(Method::POST, url)?;
+
+        let body = serde_json::to_vec(body)
+            .map_err(|e| OneInchError::InvalidInput(format!("Failed to serialize request body: {}", e)))?;
+        request.headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        request.body = Some(body);
+
+        self.execute(request).await
+    }
+
+    /// Sends the request, retrying it according to the client's retry policy,
+    /// and deserializes the response body into `T`.
+    pub(crate) async fn execute<T: DeserializeOwned>(&self, request: HttpRequest) -> Result<T, OneInchError> {
+        let policy = match &self.retry_policy {
+            Some(policy) => policy,
+            None => return self.send_once(request).await,
+        };
+
+        let idempotent = is_idempotent(&request.method);
+        let mut attempt = 1;
+
+        loop {
+            match self.send_once(request.clone()).await {
+                Err(e) if policy.should_retry(&e, attempt, idempotent) => {
+                    tokio::time::sleep(policy.delay(&e, attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    // Creates a request with the `Authorization` header set.
+    pub(crate) fn authorized_request(&self, method: Method, url: Url) -> Result<HttpRequest, OneInchError> {
+        let mut request = HttpRequest::new(method, url);
+
+        let token = HeaderValue::from_str(&self.token)
+            .map_err(|_| OneInchError::InvalidInput("Token contains invalid header characters".to_string()))?;
+        request.headers.insert(AUTHORIZATION, token);
+
+        Ok(request)
+    }
+
+    // Sends the request a single time and parses the response.
+    async fn send_once<T: DeserializeOwned>(&self, request: HttpRequest) -> Result<T, OneInchError> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        parse_response(self.transport.send(request).await?)
+    }
+}
+
+// Methods which are safe to be sent multiple times, according to RFC 9110.
+fn is_idempotent(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE)
+}
+
+// Converts raw response into `T` or into the matching `OneInchError`.
+fn parse_response<T: DeserializeOwned>(response: HttpResponse) -> Result<T, OneInchError> {
+    let status = response.status;
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(OneInchError::RateLimited { retry_after: parse_retry_after(response.headers.get(RETRY_AFTER)) });
+    }
+
+    if !status.is_success() {
+        let body = String::from_utf8_lossy(&response.body).into_owned();
+
+        return Err(OneInchError::Http {
+            status: status.as_u16(),
+            api_error: serde_json::from_str::<ApiErrorBody>(&body).ok().map(Box::new),
+            body,
+        });
+    }
+
+    // Some endpoints respond with an empty body, which is treated as `null`
+    // so it can be deserialized into `()`.
+    let body: &[u8] = if response.body.is_empty() { b"null" } else { &response.body };
+
+    serde_json::from_slice(body)
+        .map_err(|source| OneInchError::Deserialization { source, body: String::from_utf8_lossy(&response.body).into_owned() })
+}
+
+// Parses `Retry-After` header. Only the delay-seconds form is supported, as
+// it's the only one the 1inch gateway uses.
+pub(crate) fn parse_retry_after(value: Option<&reqwest::header::HeaderValue>) -> Option<Duration> {
+    value?.to_str().ok()?.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+// Converts url parsing errors into `OneInchError`.
+pub(crate) fn parse_url(url: &str) -> Result<Url, OneInchError> {
+    Url::parse(url).map_err(|e| OneInchError::InvalidInput(format!("Invalid url `{}`: {}", url, e)))
+}
+
+// Same as `parse_url` but also appends query params.
+pub(crate) fn parse_url_with_params<I, K, V>(url: &str, params: I) -> Result<Url, OneInchError>
+where
+    I: IntoIterator,
+    I::Item: std::borrow::Borrow<(K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    Url::parse_with_params(url, params).map_err(|e| OneInchError::InvalidInput(format!("Invalid url `{}`: {}", url, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after(Some(&HeaderValue::from_static("3"))), Some(Duration::from_secs(3)));
+        assert_eq!(parse_retry_after(Some(&HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"))), None);
+        assert_eq!(parse_retry_after(None), None);
+    }
+
+    #[test]
+    fn test_api_error_body_parsing() {
+        let body = r#"{"error":"Bad Request","description":"insufficient liquidity","statusCode":400,
+            "requestId":"a1b2","meta":[{"type":"amount","value":"0"}]}"#;
+
+        let parsed: ApiErrorBody = serde_json::from_str(body).unwrap();
+        assert_eq!(parsed.status_code, 400);
+        assert_eq!(parsed.request_id, "a1b2");
+        assert_eq!(parsed.meta[0].type_field, "amount");
+    }
+}
@@ -0,0 +1,67 @@
+// Minimal RLP encoder, covering what's needed to serialize transactions.
+// See https://ethereum.org/en/developers/docs/data-structures-and-encoding/rlp/
+
+// Encodes byte string.
+pub(crate) fn encode_bytes(bytes: &[u8]) -> Vec<u8> {
+    match bytes {
+        [byte] if *byte < 0x80 => vec![*byte],
+        _ => {
+            let mut encoded = encode_length(bytes.len(), 0x80);
+            encoded.extend_from_slice(bytes);
+            encoded
+        }
+    }
+}
+
+// Encodes unsigned integer given as big-endian bytes, leading zeros are
+// stripped as RLP requires.
+pub(crate) fn encode_uint(be_bytes: &[u8]) -> Vec<u8> {
+    let first_non_zero = be_bytes.iter().position(|b| *b != 0).unwrap_or(be_bytes.len());
+    encode_bytes(&be_bytes[first_non_zero..])
+}
+
+// Encodes list of already encoded items.
+pub(crate) fn encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut encoded = encode_length(payload.len(), 0xc0);
+    encoded.extend(payload);
+    encoded
+}
+
+// Encodes prefix of the string (offset 0x80) or list (offset 0xc0).
+fn encode_length(length: usize, offset: u8) -> Vec<u8> {
+    if length < 56 {
+        return vec![offset + length as u8];
+    }
+
+    let length_bytes = length.to_be_bytes();
+    let length_bytes = &length_bytes[length_bytes.iter().position(|b| *b != 0).unwrap_or(0)..];
+
+    let mut encoded = vec![offset + 55 + length_bytes.len() as u8];
+    encoded.extend_from_slice(length_bytes);
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rlp_encoding() {
+        assert_eq!(encode_bytes(b"dog"), vec![0x83, b'd', b'o', b'g']);
+        assert_eq!(encode_bytes(b""), vec![0x80]);
+        assert_eq!(encode_bytes(&[0x0f]), vec![0x0f]);
+        assert_eq!(encode_bytes(&[0x80]), vec![0x81, 0x80]);
+
+        assert_eq!(encode_uint(&0u64.to_be_bytes()), vec![0x80]);
+        assert_eq!(encode_uint(&1024u64.to_be_bytes()), vec![0x82, 0x04, 0x00]);
+
+        assert_eq!(encode_list(&[encode_bytes(b"cat"), encode_bytes(b"dog")]), b"\xc8\x83cat\x83dog".to_vec());
+        assert_eq!(encode_list(&[]), vec![0xc0]);
+
+        let long = b"Lorem ipsum dolor sit amet, consectetur adipisicing elit";
+        let mut expected = vec![0xb8, 0x38];
+        expected.extend_from_slice(long);
+        assert_eq!(encode_bytes(long), expected);
+    }
+}